@@ -0,0 +1,120 @@
+//! compares the flat, strided `DMatrix` against the `Vec<Vec<T>>` per column layout it replaced
+//!
+//! run with `cargo bench --bench dmatrix`
+
+use glucose::algebra::linear::DMatrix;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// the previous `DMatrix` layout, one heap allocation per column
+struct NestedMatrix {
+    data: Vec<Vec<f64>>,
+    size: (usize, usize),
+}
+
+impl NestedMatrix {
+    fn new(size: (usize, usize)) -> Self {
+        let data = (0..size.1)
+            .map(|n| (0..size.0).map(|m| entry(m, n)).collect())
+            .collect();
+        Self { data, size }
+    }
+
+    // the loops of the old `Mul` impl, kept as they were
+    #[allow(clippy::needless_range_loop)]
+    fn mul(&self, rhs: &Self) -> Self {
+        let mut data = vec![vec![0.0; self.size.0]; rhs.size.1];
+        for m in 0..self.size.0 {
+            for p in 0..rhs.size.1 {
+                for n in 0..self.size.1 {
+                    data[p][m] += self.data[n][m] * rhs.data[p][n];
+                }
+            }
+        }
+        Self {
+            data,
+            size: (self.size.0, rhs.size.1),
+        }
+    }
+
+    fn transpose(&self) -> Self {
+        let data = (0..self.size.0)
+            .map(|m| (0..self.size.1).map(|n| self.data[n][m]).collect())
+            .collect();
+        Self {
+            data,
+            size: (self.size.1, self.size.0),
+        }
+    }
+
+    fn sum(&self) -> f64 {
+        self.data.iter().flatten().sum()
+    }
+}
+
+fn entry(m: usize, n: usize) -> f64 {
+    ((m * 7 + n * 13) % 17) as f64 - 8.0
+}
+
+fn flat(size: (usize, usize)) -> DMatrix<f64> {
+    let mut data = Vec::with_capacity(size.0 * size.1);
+    for n in 0..size.1 {
+        data.extend((0..size.0).map(|m| entry(m, n)));
+    }
+    DMatrix::from_vec(data, size)
+}
+
+fn bench<R>(name: &str, iterations: u32, mut f: impl FnMut() -> R) -> Duration {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let per_iteration = start.elapsed() / iterations;
+    println!("{:<32} {:>12.2?}", name, per_iteration);
+    per_iteration
+}
+
+fn compare(name: &str, nested: Duration, flat: Duration) {
+    println!(
+        "{:<32} {:>11.2}x\n",
+        format!("{} speedup", name),
+        nested.as_secs_f64() / flat.as_secs_f64()
+    );
+}
+
+fn main() {
+    for &n in &[16, 64, 256] {
+        let iterations = (1 << 24) / (n * n * n) as u32 + 1;
+        println!("--- {0}x{0} ---", n);
+
+        let nested = bench("nested construct", iterations * 16, || {
+            NestedMatrix::new((n, n))
+        });
+        let contiguous = bench("flat construct", iterations * 16, || flat((n, n)));
+        compare("construct", nested, contiguous);
+
+        let (a, b) = (NestedMatrix::new((n, n)), NestedMatrix::new((n, n)));
+        let (c, d) = (flat((n, n)), flat((n, n)));
+        let nested = bench("nested mul", iterations, || a.mul(&b));
+        let contiguous = bench("flat mul", iterations, || {
+            // allocates the output like the nested side, but doesn't copy the operands
+            let mut out = DMatrix::from_element((n, n), 0.0);
+            c.mul_to(&d, &mut out);
+            out
+        });
+        compare("mul", nested, contiguous);
+
+        let nested = bench("nested transpose", iterations * 16, || a.transpose());
+        let contiguous = bench("flat transpose", iterations * 16, || {
+            c.transpose_view().clone_owned()
+        });
+        compare("transpose", nested, contiguous);
+
+        let nested = bench("nested sum", iterations * 16, || a.sum());
+        let contiguous = bench("flat sum", iterations * 16, || {
+            c.as_slice().iter().sum::<f64>()
+        });
+        compare("sum", nested, contiguous);
+    }
+}
@@ -0,0 +1,377 @@
+use crate::algebra::linear::pga::{Line, Motor, Plane, Point};
+use crate::algebra::linear::{
+    Bivector2, Bivector3, DVector, DualQuaternion, GenericMatrix, Multivector, Quaternion, Rotor2,
+    Rotor3, Storage,
+};
+
+/// approximate equality for floating point values and everything built out of them
+///
+/// composite types compare element wise and are only equal if all elements are.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+
+    fn default_max_relative() -> Self::Epsilon;
+
+    fn default_max_ulps() -> u32;
+
+    /// `|a - b| <= epsilon`
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// `|a - b| <= epsilon` or `|a - b| <= max(|a|, |b|) * max_relative`
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    /// `|a - b| <= epsilon` or at most `max_ulps` representable floats between `a` and `b`
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    /// relative comparison with the default tolerances
+    #[inline]
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.relative_eq(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    ($($t:ident => $bits:ty),+) => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                #[inline]
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                #[inline]
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    (self - other).abs() <= epsilon
+                }
+
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+                    let diff = (self - other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+                    diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                #[inline]
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true;
+                    }
+                    if self.is_nan() || other.is_nan() {
+                        return false;
+                    }
+                    if self.is_sign_positive() != other.is_sign_positive() {
+                        return false;
+                    }
+                    let (lhs, rhs) = (self.to_bits(), other.to_bits());
+                    let ulps = if lhs > rhs { lhs - rhs } else { rhs - lhs };
+                    ulps <= max_ulps as $bits
+                }
+            }
+        )+
+    };
+}
+
+impl_approx_eq_float!(f32 => u32, f64 => u64);
+
+/// compares two equally long iterators element wise
+fn all_approx_eq<'a, T: ApproxEq + 'a>(
+    lhs: impl IntoIterator<Item = &'a T>,
+    rhs: impl IntoIterator<Item = &'a T>,
+    eq: impl Fn(&T, &T) -> bool,
+) -> bool {
+    lhs.into_iter().zip(rhs).all(|(a, b)| eq(a, b))
+}
+
+macro_rules! forward_approx_eq {
+    () => {
+        #[inline]
+        fn default_epsilon() -> T::Epsilon {
+            T::default_epsilon()
+        }
+
+        #[inline]
+        fn default_max_relative() -> T::Epsilon {
+            T::default_max_relative()
+        }
+
+        #[inline]
+        fn default_max_ulps() -> u32 {
+            T::default_max_ulps()
+        }
+    };
+}
+
+impl<T: ApproxEq, S: Storage<Element = T>> ApproxEq for GenericMatrix<S> {
+    type Epsilon = T::Epsilon;
+
+    forward_approx_eq!();
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.size() == other.size()
+            && all_approx_eq(self.iter(), other.iter(), |a, b| a.abs_diff_eq(b, epsilon))
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.size() == other.size()
+            && all_approx_eq(self.iter(), other.iter(), |a, b| {
+                a.relative_eq(b, epsilon, max_relative)
+            })
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.size() == other.size()
+            && all_approx_eq(self.iter(), other.iter(), |a, b| {
+                a.ulps_eq(b, epsilon, max_ulps)
+            })
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for DVector<T> {
+    type Epsilon = T::Epsilon;
+
+    forward_approx_eq!();
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.len == other.len
+            && all_approx_eq(&self.data, &other.data, |a, b| a.abs_diff_eq(b, epsilon))
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.len == other.len
+            && all_approx_eq(&self.data, &other.data, |a, b| {
+                a.relative_eq(b, epsilon, max_relative)
+            })
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.len == other.len
+            && all_approx_eq(&self.data, &other.data, |a, b| {
+                a.ulps_eq(b, epsilon, max_ulps)
+            })
+    }
+}
+
+impl<T: ApproxEq, const P: usize, const Q: usize, const R: usize> ApproxEq
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Epsilon = T::Epsilon;
+
+    forward_approx_eq!();
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        all_approx_eq(&self.data, &other.data, |a, b| a.abs_diff_eq(b, epsilon))
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        all_approx_eq(&self.data, &other.data, |a, b| {
+            a.relative_eq(b, epsilon, max_relative)
+        })
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        all_approx_eq(&self.data, &other.data, |a, b| {
+            a.ulps_eq(b, epsilon, max_ulps)
+        })
+    }
+}
+
+/// element wise comparison of structs with named fields
+macro_rules! impl_approx_eq_fields {
+    ($($ty:ident { $($field:ident),* });* $(;)?) => {
+        $(
+            impl<T: ApproxEq> ApproxEq for $ty<T> {
+                type Epsilon = T::Epsilon;
+
+                forward_approx_eq!();
+
+                #[inline]
+                fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+                    $(self.$field.abs_diff_eq(&other.$field, epsilon))&&*
+                }
+
+                #[inline]
+                fn relative_eq(
+                    &self,
+                    other: &Self,
+                    epsilon: T::Epsilon,
+                    max_relative: T::Epsilon,
+                ) -> bool {
+                    $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&*
+                }
+
+                #[inline]
+                fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                    $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&*
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq_fields!(
+    Bivector2 { data };
+    Bivector3 { xy, xz, yz };
+    Rotor2 { scalar, bivector };
+    Rotor3 { scalar, bivector };
+    Quaternion { x, y, z, w };
+    DualQuaternion { real, dual };
+    Plane { e0, e1, e2, e3 };
+    Line { e01, e02, e03, e12, e31, e23 };
+    Point { e032, e013, e021, e123 };
+    Motor { s, e23, e31, e12, e01, e02, e03, e0123 };
+);
+
+/// asserts that two `ApproxEq` values are approximately equal
+///
+/// ```ignore
+/// assert_approx_eq!(a, b); // relative comparison with the default tolerances
+/// assert_approx_eq!(a, b, epsilon = 1e-12); // absolute comparison
+/// assert_approx_eq!(a, b, epsilon = 1e-12, max_relative = 1e-9);
+/// assert_approx_eq!(a, b, epsilon = 1e-12, max_ulps = 8);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($lhs:expr, $rhs:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                if !$crate::approx_eq!(lhs, rhs $(, $name = $value)*) {
+                    panic!(
+                        "assertion failed: `{} ≈ {}`\n  left: `{:?}`\n right: `{:?}`",
+                        stringify!($lhs),
+                        stringify!($rhs),
+                        lhs,
+                        rhs,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// asserts that two `ApproxEq` values are not approximately equal, takes the same arguments as `assert_approx_eq!`
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($lhs:expr, $rhs:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                if $crate::approx_eq!(lhs, rhs $(, $name = $value)*) {
+                    panic!(
+                        "assertion failed: `{} ≉ {}`\n  left: `{:?}`\n right: `{:?}`",
+                        stringify!($lhs),
+                        stringify!($rhs),
+                        lhs,
+                        rhs,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// dispatches to the comparison of `ApproxEq` matching the given tolerances
+#[doc(hidden)]
+#[macro_export]
+macro_rules! approx_eq {
+    ($lhs:expr, $rhs:expr) => {
+        $crate::algebra::linear::ApproxEq::approx_eq($lhs, $rhs)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $eps:expr) => {
+        $crate::algebra::linear::ApproxEq::abs_diff_eq($lhs, $rhs, $eps)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $eps:expr, max_relative = $rel:expr) => {
+        $crate::algebra::linear::ApproxEq::relative_eq($lhs, $rhs, $eps, $rel)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $eps:expr, max_ulps = $ulps:expr) => {
+        $crate::algebra::linear::ApproxEq::ulps_eq($lhs, $rhs, $eps, $ulps)
+    };
+}
+
+#[cfg(test)]
+mod approx_tests {
+    use crate::algebra::linear::{ApproxEq, Bivector2, DMatrix, DVector, Matrix, Rotor2};
+
+    #[test]
+    fn scalars() {
+        assert!(1.0f64.abs_diff_eq(&1.05, 0.1));
+        assert!(!1.0f64.abs_diff_eq(&1.2, 0.1));
+
+        assert!(1e10f64.relative_eq(&(1e10 + 1.0), 0.0, 1e-9));
+        assert!(!1e-10f64.relative_eq(&2e-10, 0.0, 1e-9));
+        assert!(f64::INFINITY.relative_eq(&f64::INFINITY, 0.0, 0.0));
+        assert!(!f64::NAN.relative_eq(&f64::NAN, 1.0, 1.0));
+
+        let next = f32::from_bits(1.0f32.to_bits() + 2);
+        assert!(1.0f32.ulps_eq(&next, 0.0, 2));
+        assert!(!1.0f32.ulps_eq(&next, 0.0, 1));
+        assert!(!1.0f32.ulps_eq(&-1.0, 0.0, u32::MAX));
+        assert!(0.0f32.ulps_eq(&-0.0, 0.0, 0));
+
+        assert!((0.1f64 + 0.2).approx_eq(&0.3));
+        assert_approx_eq!(0.1f64 + 0.2, 0.3);
+        assert_approx_eq!(1.0f64, 1.001, epsilon = 0.01);
+        assert_approx_eq!(100.0f64, 101.0, epsilon = 0.0, max_relative = 0.01);
+        assert_approx_ne!(1.0f64, 1.1, epsilon = 0.01, max_ulps = 4);
+    }
+
+    #[test]
+    fn composites() {
+        let mat = Matrix::<f64, 2, 2>::new([[0.1 + 0.2, 1.0], [2.0, 3.0]]);
+        assert_approx_eq!(mat, Matrix::new([[0.3, 1.0], [2.0, 3.0]]));
+        assert_approx_ne!(mat, Matrix::new([[0.3, 1.0], [2.0, 3.1]]));
+
+        let dmat = DMatrix::new(vec![vec![0.1 + 0.2], vec![1.0]]);
+        assert_approx_eq!(dmat, DMatrix::new(vec![vec![0.3], vec![1.0]]));
+        assert_approx_ne!(dmat, DMatrix::new(vec![vec![0.3, 1.0]]));
+
+        let dvec = DVector::new(vec![0.1 + 0.2, 1.0]);
+        assert_approx_eq!(dvec, DVector::new(vec![0.3, 1.0]), epsilon = 1e-12);
+        assert_approx_ne!(dvec, DVector::new(vec![0.3]));
+
+        let rotor = Rotor2::new(0.1f32 + 0.2, Bivector2::new(0.5));
+        assert_approx_eq!(rotor, Rotor2::new(0.3, Bivector2::new(0.5)));
+        assert_approx_ne!(rotor, Rotor2::new(0.3, Bivector2::new(-0.5)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn assert_fails() {
+        assert_approx_eq!(1.0f64, 1.1);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::algebra::linear::scalar::Scalar;
 use fructose::operators::{
-    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Multiplicative,
+    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedOps, ClosedSub, Multiplicative,
 };
 use fructose::properties::general::{Associative, Commutative, Identity, Invertible, Set, Total};
 use fructose::specific::complex::Real;
@@ -190,6 +190,140 @@ impl<T: Scalar + ClosedNeg> Neg for Bivector2<T> {
     }
 }
 
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialOrd, PartialEq)]
+pub struct Bivector3<T> {
+    pub xy: T,
+    pub xz: T,
+    pub yz: T,
+}
+
+impl<T> Bivector3<T> {
+    pub const fn new(xy: T, xz: T, yz: T) -> Self {
+        Self { xy, xz, yz }
+    }
+
+    #[inline]
+    pub fn layout() -> std::alloc::Layout {
+        std::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<T>())
+            .unwrap()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 3) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 3) }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self as *const Self as *const T
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut Self as *mut T
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> Bivector3<T> {
+    pub fn dot(self, rhs: Self) -> T {
+        self.xy * rhs.xy + self.xz * rhs.xz + self.yz * rhs.yz
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps> Bivector3<T> {
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&mut self) {
+        *self /= self.magnitude();
+    }
+
+    pub fn normalized(&self) -> Self {
+        let mut bivec = *self;
+        bivec.normalize();
+        bivec
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Add for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Bivector3<T>) -> Self {
+        Self::new(self.xy + rhs.xy, self.xz + rhs.xz, self.yz + rhs.yz)
+    }
+}
+
+impl<T: Scalar + ClosedAdd> AddAssign for Bivector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Bivector3<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar + ClosedSub> Sub for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Bivector3<T>) -> Self {
+        Self::new(self.xy - rhs.xy, self.xz - rhs.xz, self.yz - rhs.yz)
+    }
+}
+
+impl<T: Scalar + ClosedSub> SubAssign for Bivector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Bivector3<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar + ClosedMul> Mul<T> for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.xy * rhs, self.xz * rhs, self.yz * rhs)
+    }
+}
+
+impl<T: Scalar + ClosedMul> MulAssign<T> for Bivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar + ClosedDiv> Div<T> for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.xy / rhs, self.xz / rhs, self.yz / rhs)
+    }
+}
+
+impl<T: Scalar + ClosedDiv> DivAssign<T> for Bivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for Bivector3<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.xy, -self.xz, -self.yz)
+    }
+}
+
 impl<T: Scalar + ClosedAdd> Set<Additive> for Bivector2<T> {
     fn operate(&self, rhs: Self) -> Self {
         *self + rhs
@@ -254,7 +388,41 @@ impl<T: Scalar + ClosedMul + Invertible<Multiplicative>> Invertible<Multiplicati
     for Bivector2<T>
 {
     fn inverse(&self) -> Self {
-        unimplemented!()
+        Self::new(<T as Invertible<Multiplicative>>::inverse(&self.data))
+    }
+
+    fn inverted(&mut self) {
+        *self = <Self as Invertible<Multiplicative>>::inverse(self);
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Set<Additive> for Bivector3<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self + rhs
+    }
+}
+
+impl<T: Scalar + ClosedAdd + Total<Additive>> Total<Additive> for Bivector3<T> {}
+
+impl<T: Scalar + ClosedAdd + Associative<Additive>> Associative<Additive> for Bivector3<T> {}
+
+impl<T: Scalar + ClosedAdd + Commutative<Additive>> Commutative<Additive> for Bivector3<T> {}
+
+impl<T: Scalar + Identity<Additive> + ClosedAdd> Identity<Additive> for Bivector3<T> {
+    fn identity() -> Self {
+        Self::new(T::identity(), T::identity(), T::identity())
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == Self::identity()
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedNeg + Invertible<Additive>> Invertible<Additive>
+    for Bivector3<T>
+{
+    fn inverse(&self) -> Self {
+        -*self
     }
 
     fn inverted(&mut self) {
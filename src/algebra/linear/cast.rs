@@ -0,0 +1,367 @@
+use crate::algebra::linear::{Bivector2, DMatrix, DVector, Matrix, Rotor2, Scalar};
+use std::convert::TryFrom;
+
+/// conversion with the semantics of `as`: truncating, saturating and rounding where necessary
+pub trait LossyCast<U> {
+    fn lossy_cast(self) -> U;
+}
+
+/// conversion that only succeeds if the value is exactly representable in `U`
+///
+/// fractions, out of range values and NaN are rejected
+pub trait CheckedCast<U> {
+    fn checked_cast(self) -> Option<U>;
+}
+
+macro_rules! impl_lossy_casts {
+    ($($from:ty),+ => $to:tt) => {
+        $( impl_lossy_casts!(@single $from => $to); )+
+    };
+    (@single $from:ty => [$($to:ty),+]) => {
+        $(
+            impl LossyCast<$to> for $from {
+                #[inline]
+                fn lossy_cast(self) -> $to {
+                    self as $to
+                }
+            }
+        )+
+    };
+}
+
+impl_lossy_casts! {
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+    => [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64]
+}
+
+/// `$kind` picks how exactness is checked, `as` saturates and rounds at the range boundaries,
+/// so casting the result back is not a reliable test on its own
+macro_rules! impl_checked_casts {
+    ($kind:ident: $($from:ty),+ => $to:tt) => {
+        $( impl_checked_casts!(@$kind $from => $to); )+
+    };
+    (@int_to_int $from:ty => [$($to:ty),+]) => {
+        $(
+            impl CheckedCast<$to> for $from {
+                #[inline]
+                fn checked_cast(self) -> Option<$to> {
+                    <$to>::try_from(self).ok()
+                }
+            }
+        )+
+    };
+    (@float_to_int $from:ty => [$($to:ty),+]) => {
+        $(
+            impl CheckedCast<$to> for $from {
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn checked_cast(self) -> Option<$to> {
+                    // both bounds are powers of two and exact, the upper one is `MAX + 1`
+                    let min = <$to>::MIN as $from;
+                    let max = (<$to>::MAX / 2 + 1) as $from * 2.0;
+                    if self >= min && self < max && self.fract() == 0.0 {
+                        Some(self as $to)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+    (@int_to_float $from:ty => [$($to:ty),+]) => {
+        $(
+            impl CheckedCast<$to> for $from {
+                #[inline]
+                fn checked_cast(self) -> Option<$to> {
+                    let value = self as $to;
+                    if CheckedCast::<$from>::checked_cast(value) == Some(self) {
+                        Some(value)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+    (@float_to_float $from:ty => [$($to:ty),+]) => {
+        $(
+            impl CheckedCast<$to> for $from {
+                #[inline]
+                #[allow(clippy::float_cmp)]
+                fn checked_cast(self) -> Option<$to> {
+                    let value = self as $to;
+                    if value as $from == self {
+                        Some(value)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_checked_casts! {
+    int_to_int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+    => [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+}
+impl_checked_casts! {
+    float_to_int: f32, f64
+    => [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+}
+impl_checked_casts! {
+    int_to_float: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize => [f32, f64]
+}
+impl_checked_casts! {
+    float_to_float: f32, f64 => [f32, f64]
+}
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    /// lossless conversion of every entry
+    #[inline]
+    pub fn cast<U: Scalar + From<T>>(&self) -> Matrix<U, { M }, { N }> {
+        let mut mat = Matrix::default();
+        for m in 0..M {
+            for n in 0..N {
+                mat[[m, n]] = U::from(self[[m, n]]);
+            }
+        }
+        mat
+    }
+
+    /// converts every entry like `as` would
+    #[inline]
+    pub fn cast_lossy<U: Scalar>(&self) -> Matrix<U, { M }, { N }>
+    where
+        T: LossyCast<U>,
+    {
+        let mut mat = Matrix::default();
+        for m in 0..M {
+            for n in 0..N {
+                mat[[m, n]] = self[[m, n]].lossy_cast();
+            }
+        }
+        mat
+    }
+
+    /// returns `None` if any entry is not exactly representable in `U`
+    #[inline]
+    pub fn try_cast<U: Scalar>(&self) -> Option<Matrix<U, { M }, { N }>>
+    where
+        T: CheckedCast<U>,
+    {
+        let mut mat = Matrix::default();
+        for m in 0..M {
+            for n in 0..N {
+                mat[[m, n]] = self[[m, n]].checked_cast()?;
+            }
+        }
+        Some(mat)
+    }
+}
+
+impl<T: Scalar> DMatrix<T> {
+    #[inline]
+    pub fn cast<U: Scalar + From<T>>(&self) -> DMatrix<U> {
+        DMatrix::from_vec(
+            self.as_slice().iter().map(|e| U::from(*e)).collect(),
+            self.size(),
+        )
+    }
+
+    #[inline]
+    pub fn cast_lossy<U: Scalar>(&self) -> DMatrix<U>
+    where
+        T: LossyCast<U>,
+    {
+        DMatrix::from_vec(
+            self.as_slice().iter().map(|e| e.lossy_cast()).collect(),
+            self.size(),
+        )
+    }
+
+    #[inline]
+    pub fn try_cast<U: Scalar>(&self) -> Option<DMatrix<U>>
+    where
+        T: CheckedCast<U>,
+    {
+        let data = self
+            .as_slice()
+            .iter()
+            .map(|e| e.checked_cast())
+            .collect::<Option<Vec<U>>>()?;
+        Some(DMatrix::from_vec(data, self.size()))
+    }
+}
+
+impl<T: Scalar> DVector<T> {
+    #[inline]
+    pub fn cast<U: Scalar + From<T>>(&self) -> DVector<U> {
+        DVector::new(self.data.iter().map(|e| U::from(*e)).collect())
+    }
+
+    #[inline]
+    pub fn cast_lossy<U: Scalar>(&self) -> DVector<U>
+    where
+        T: LossyCast<U>,
+    {
+        DVector::new(self.data.iter().map(|e| e.lossy_cast()).collect())
+    }
+
+    #[inline]
+    pub fn try_cast<U: Scalar>(&self) -> Option<DVector<U>>
+    where
+        T: CheckedCast<U>,
+    {
+        let data = self
+            .data
+            .iter()
+            .map(|e| e.checked_cast())
+            .collect::<Option<Vec<U>>>()?;
+        Some(DVector::new(data))
+    }
+}
+
+impl<T: Scalar> Bivector2<T> {
+    #[inline]
+    pub fn cast<U: Scalar + From<T>>(&self) -> Bivector2<U> {
+        Bivector2::new(U::from(self.data))
+    }
+
+    #[inline]
+    pub fn cast_lossy<U: Scalar>(&self) -> Bivector2<U>
+    where
+        T: LossyCast<U>,
+    {
+        Bivector2::new(self.data.lossy_cast())
+    }
+
+    #[inline]
+    pub fn try_cast<U: Scalar>(&self) -> Option<Bivector2<U>>
+    where
+        T: CheckedCast<U>,
+    {
+        Some(Bivector2::new(self.data.checked_cast()?))
+    }
+}
+
+impl<T: Scalar> Rotor2<T> {
+    #[inline]
+    pub fn cast<U: Scalar + From<T>>(&self) -> Rotor2<U> {
+        Rotor2::new(U::from(self.scalar), self.bivector.cast())
+    }
+
+    #[inline]
+    pub fn cast_lossy<U: Scalar>(&self) -> Rotor2<U>
+    where
+        T: LossyCast<U>,
+    {
+        Rotor2::new(self.scalar.lossy_cast(), self.bivector.cast_lossy())
+    }
+
+    #[inline]
+    pub fn try_cast<U: Scalar>(&self) -> Option<Rotor2<U>>
+    where
+        T: CheckedCast<U>,
+    {
+        Some(Rotor2::new(
+            self.scalar.checked_cast()?,
+            self.bivector.try_cast()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod cast_tests {
+    use crate::algebra::linear::{Bivector2, CheckedCast, DMatrix, DVector, Matrix, Rotor2};
+
+    #[test]
+    fn matrix() {
+        let mat = Matrix::<i32, 2, 2>::new([[1, -2], [3, 4]]);
+        assert_eq!(mat.cast::<f64>(), Matrix::new([[1.0, -2.0], [3.0, 4.0]]));
+        assert_eq!(
+            mat.cast_lossy::<f32>(),
+            Matrix::new([[1.0, -2.0], [3.0, 4.0]])
+        );
+        assert_eq!(mat.try_cast::<i8>(), Some(Matrix::new([[1, -2], [3, 4]])));
+        assert_eq!(mat.try_cast::<u32>(), None);
+
+        let mat = Matrix::<f64, 1, 2>::new([[1.5], [300.0]]);
+        assert_eq!(mat.cast_lossy::<u8>(), Matrix::new([[1], [255]]));
+        assert_eq!(mat.try_cast::<u16>(), None);
+        assert_eq!(mat.try_cast::<f32>(), Some(Matrix::new([[1.5], [300.0]])));
+    }
+
+    #[test]
+    fn dynamic() {
+        let mat = DMatrix::new(vec![vec![1u8, 2], vec![3, 200]]);
+        assert_eq!(mat.cast::<u16>().as_slice(), [1, 2, 3, 200]);
+        assert_eq!(mat.cast_lossy::<i8>().as_slice(), [1, 2, 3, -56]);
+        assert!(mat.try_cast::<i8>().is_none());
+
+        let vec = DVector::new(vec![1.0f32, -2.5]);
+        assert_eq!(vec.cast::<f64>().data, vec![1.0, -2.5]);
+        assert_eq!(vec.cast_lossy::<i32>().data, vec![1, -2]);
+        assert!(vec.try_cast::<i32>().is_none());
+    }
+
+    #[test]
+    fn geometric() {
+        let rotor = Rotor2::new(1.0f64, Bivector2::new(-0.5));
+        assert_eq!(
+            rotor.cast_lossy::<f32>(),
+            Rotor2::new(1.0, Bivector2::new(-0.5))
+        );
+        assert_eq!(rotor.try_cast::<i32>(), None);
+        assert_eq!(Bivector2::new(2i16).cast::<i64>(), Bivector2::new(2));
+    }
+
+    #[test]
+    fn boundaries() {
+        // int to int
+        assert_eq!(CheckedCast::<u8>::checked_cast(255i32), Some(255u8));
+        assert_eq!(CheckedCast::<u8>::checked_cast(256i32), None);
+        assert_eq!(CheckedCast::<i64>::checked_cast(u64::MAX), None);
+        assert_eq!(CheckedCast::<u64>::checked_cast(-1i8), None);
+        assert_eq!(
+            CheckedCast::<i128>::checked_cast(i128::MIN),
+            Some(i128::MIN)
+        );
+
+        // float to int
+        let two_63 = 9_223_372_036_854_775_808.0f64;
+        assert_eq!(CheckedCast::<i64>::checked_cast(two_63), None);
+        assert_eq!(CheckedCast::<i64>::checked_cast(-two_63), Some(i64::MIN));
+        assert_eq!(CheckedCast::<u64>::checked_cast(two_63), Some(1u64 << 63));
+        assert_eq!(CheckedCast::<u8>::checked_cast(255.0f32), Some(255u8));
+        assert_eq!(CheckedCast::<u8>::checked_cast(256.0f32), None);
+        assert_eq!(CheckedCast::<u8>::checked_cast(-0.5f32), None);
+        assert_eq!(CheckedCast::<i32>::checked_cast(f64::NAN), None);
+        assert_eq!(CheckedCast::<i32>::checked_cast(f64::INFINITY), None);
+        assert_eq!(
+            CheckedCast::<u128>::checked_cast(f32::MAX),
+            Some(f32::MAX as u128)
+        );
+
+        // int to float
+        assert_eq!(CheckedCast::<f32>::checked_cast(i32::MAX), None);
+        assert_eq!(CheckedCast::<f64>::checked_cast(i64::MAX), None);
+        assert_eq!(CheckedCast::<f64>::checked_cast(i64::MIN), Some(-two_63));
+        assert_eq!(
+            CheckedCast::<f32>::checked_cast(1i32 << 24),
+            Some(16_777_216.0f32)
+        );
+        assert_eq!(CheckedCast::<f32>::checked_cast((1i32 << 24) + 1), None);
+        assert_eq!(
+            CheckedCast::<f64>::checked_cast(u32::MAX),
+            Some(4_294_967_295.0)
+        );
+        assert_eq!(CheckedCast::<f32>::checked_cast(u128::MAX), None);
+
+        // float to float
+        assert_eq!(CheckedCast::<f32>::checked_cast(0.1f64), None);
+        assert_eq!(CheckedCast::<f32>::checked_cast(0.5f64), Some(0.5f32));
+        assert_eq!(CheckedCast::<f32>::checked_cast(f64::MAX), None);
+        assert_eq!(CheckedCast::<f64>::checked_cast(f32::NAN), None);
+    }
+}
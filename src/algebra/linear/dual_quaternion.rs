@@ -0,0 +1,399 @@
+use crate::algebra::linear::mat::SquareMatrix;
+use crate::algebra::linear::quaternion::Quaternion;
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::vec::Vector;
+use fructose::operators::{ClosedAdd, ClosedNeg, ClosedOps};
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::specific::complex::Real;
+use std::ops::{Add, Mul, MulAssign, Neg};
+
+/// dual quaternion `real + ε dual` with `ε² = 0`
+///
+/// unit dual quaternions are rigid transforms, a rotation by `real` followed by a translation `t`
+/// stored as `dual = t * real / 2`. `lhs * rhs` is the transform that applies `rhs` first.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct DualQuaternion<T> {
+    pub real: Quaternion<T>,
+    pub dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    #[inline]
+    pub const fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+
+    #[inline]
+    pub fn layout() -> std::alloc::Layout {
+        std::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<T>())
+            .unwrap()
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> DualQuaternion<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(Quaternion::identity(), Quaternion::default())
+    }
+
+    /// rotates by the normalized `rotation` first and translates by `translation` second
+    #[inline]
+    pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vector<T, 3>) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        let translation = Quaternion::from_scalar_vector(T::zero(), translation);
+        Self::new(rotation, translation * rotation * half)
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        Self::from_rotation_translation(Quaternion::identity(), translation)
+    }
+
+    #[inline]
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self::new(rotation, Quaternion::default())
+    }
+
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    /// the translation of a unit dual quaternion, `2 * dual * real^*`
+    #[inline]
+    pub fn translation(&self) -> Vector<T, 3> {
+        let two = T::one() + T::one();
+        (self.dual * self.real.conjugated()).vector() * two
+    }
+
+    #[inline]
+    pub fn into_rotation_translation(self) -> (Quaternion<T>, Vector<T, 3>) {
+        (self.rotation(), self.translation())
+    }
+
+    /// conjugates both parts, this is the inverse of a unit dual quaternion
+    #[inline]
+    pub fn conjugate(&mut self) {
+        self.real.conjugate();
+        self.dual.conjugate();
+    }
+
+    #[inline]
+    pub fn conjugated(&self) -> Self {
+        let mut dq = *self;
+        dq.conjugate();
+        dq
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        self.real * point + self.translation()
+    }
+
+    /// rotates `vector`, directions are not translated
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        self.real * vector
+    }
+
+    /// the homogeneous transform of a unit dual quaternion
+    #[inline]
+    pub fn into_matrix(self) -> SquareMatrix<T, 4> {
+        let mut mat = self.real.into_matrix4();
+        let translation = self.translation();
+        for i in 0..3 {
+            mat[[i, 3]] = translation[i];
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> DualQuaternion<T> {
+    /// scales the real part to unit length and makes the dual part orthogonal to it
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.real.magnitude();
+        let real = self.real * (T::one() / mag);
+        let dual = self.dual * (T::one() / mag);
+        self.dual = dual - real * real.dot(dual);
+        self.real = real;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut dq = *self;
+        dq.normalize();
+        dq
+    }
+
+    /// raises a unit dual quaternion to the power `t` by scaling its screw motion
+    pub fn powf(&self, t: T) -> Self {
+        let two = T::one() + T::one();
+        let vector = self.real.vector();
+        let sin_half = vector.magnitude();
+        let translation = self.translation();
+
+        // a pure translation has no screw axis
+        if sin_half <= <T as Float>::EPSILON {
+            return Self::from_rotation_translation(self.real, translation * t);
+        }
+
+        let half_angle = sin_half.atan2(self.real.w);
+        let axis = vector * (T::one() / sin_half);
+        let pitch = translation.dot(axis);
+        let cot_half = self.real.w / sin_half;
+        let mut moment = translation.cross(axis);
+        for i in 0..3 {
+            moment[i] = (moment[i] + (translation[i] - axis[i] * pitch) * cot_half) / two;
+        }
+
+        let half_angle = half_angle * t;
+        let half_pitch = pitch * t / two;
+        let (sin, cos) = half_angle.sin_cos();
+
+        let mut dual = Vector::<T, 3>::default();
+        for i in 0..3 {
+            dual[i] = moment[i] * sin + axis[i] * half_pitch * cos;
+        }
+        Self::new(
+            Quaternion::from_scalar_vector(cos, axis * sin),
+            Quaternion::from_scalar_vector(-half_pitch * sin, dual),
+        )
+    }
+
+    /// screw linear interpolation, moves along the screw motion from `self` to `other` with constant speed
+    ///
+    /// both have to be normalized, `t = 0` returns `self` and `t = 1` returns `other`.
+    pub fn sclerp(&self, other: &Self, t: T) -> Self {
+        let mut difference = self.conjugated() * *other;
+        // take the shorter way around, q and -q are the same rotation
+        if difference.real.w < T::zero() {
+            difference = -difference;
+        }
+        *self * difference.powf(t)
+    }
+
+    /// dual quaternion linear blending, the normalized weighted sum of `transforms`
+    ///
+    /// the rotations are flipped onto the hemisphere of the first one to blend along the shortest path.
+    /// panics if `transforms` is empty or the weighted rotations cancel out.
+    pub fn linear_blend(transforms: &[Self], weights: &[T]) -> Self {
+        assert_eq!(
+            transforms.len(),
+            weights.len(),
+            "every transform needs a weight"
+        );
+        assert!(!transforms.is_empty(), "nothing to blend");
+        let pivot = transforms[0].real;
+        let mut blend = Self::new(Quaternion::default(), Quaternion::default());
+        for (dq, weight) in transforms.iter().zip(weights) {
+            let weight = if dq.real.dot(pivot) < T::zero() {
+                -*weight
+            } else {
+                *weight
+            };
+            blend = blend + *dq * weight;
+        }
+        assert!(
+            blend.real.dot(blend.real) > T::zero(),
+            "the weights cancel out"
+        );
+        blend.normalized()
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg> From<SquareMatrix<T, 4>> for DualQuaternion<T> {
+    /// the rigid transform of a homogeneous matrix without scale or shear
+    #[inline]
+    fn from(rhs: SquareMatrix<T, 4>) -> Self {
+        let translation = Vector::from([rhs[[0, 3]], rhs[[1, 3]], rhs[[2, 3]]]);
+        Self::from_rotation_translation(Quaternion::from(rhs), translation)
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<DualQuaternion<T>>
+    for SquareMatrix<T, 4>
+{
+    #[inline]
+    fn from(rhs: DualQuaternion<T>) -> Self {
+        rhs.into_matrix()
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<(Quaternion<T>, Vector<T, 3>)>
+    for DualQuaternion<T>
+{
+    #[inline]
+    fn from(rhs: (Quaternion<T>, Vector<T, 3>)) -> Self {
+        Self::from_rotation_translation(rhs.0, rhs.1)
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<DualQuaternion<T>>
+    for (Quaternion<T>, Vector<T, 3>)
+{
+    #[inline]
+    fn from(rhs: DualQuaternion<T>) -> Self {
+        rhs.into_rotation_translation()
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Add for DualQuaternion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul for DualQuaternion<T> {
+    type Output = Self;
+
+    /// composes the transforms
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.real * rhs.real,
+            self.real * rhs.dual + self.dual * rhs.real,
+        )
+    }
+}
+
+impl<T: Scalar + ClosedOps> MulAssign for DualQuaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul<T> for DualQuaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.real * rhs, self.dual * rhs)
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for DualQuaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.real, -self.dual)
+    }
+}
+
+#[cfg(test)]
+mod dual_quaternion_tests {
+    use crate::algebra::linear::{DualQuaternion, Quaternion, SquareMatrix, Vector};
+    use crate::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn transform() -> DualQuaternion<f64> {
+        let rotation = Quaternion::from_axis_angle(Vector::from([1.0, 2.0, -1.0]), 0.8);
+        DualQuaternion::from_rotation_translation(rotation, Vector::from([3.0, -1.0, 2.0]))
+    }
+
+    #[test]
+    fn rigid_transform() {
+        let dq = transform();
+        let point = Vector::from([0.5, 1.0, -2.0]);
+        let expected = dq.real * point + Vector::from([3.0, -1.0, 2.0]);
+        assert_approx_eq!(dq.transform_point(point), expected, epsilon = 1e-12);
+        assert_approx_eq!(dq.transform_vector(point), dq.real * point, epsilon = 1e-12);
+
+        let (rotation, translation) = dq.into_rotation_translation();
+        assert_approx_eq!(rotation, dq.real);
+        assert_approx_eq!(translation, Vector::from([3.0, -1.0, 2.0]), epsilon = 1e-12);
+
+        let mat = dq.into_matrix();
+        assert_approx_eq!(point.transform_point(&mat), expected, epsilon = 1e-12);
+        assert_approx_eq!(DualQuaternion::from(mat), dq, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn composition() {
+        let a = transform();
+        let b = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), FRAC_PI_2),
+            Vector::from([1.0, 0.0, 0.0]),
+        );
+        let point = Vector::from([0.5, 1.0, -2.0]);
+        assert_approx_eq!(
+            (a * b).transform_point(point),
+            a.transform_point(b.transform_point(point)),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(
+            (a * b).into_matrix(),
+            a.into_matrix() * b.into_matrix(),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(
+            a * a.conjugated(),
+            DualQuaternion::identity(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn sclerp() {
+        let a = transform();
+        let b = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector::from([0.0, 1.0, 1.0]), 2.0),
+            Vector::from([-1.0, 4.0, 0.0]),
+        );
+        assert_approx_eq!(a.sclerp(&b, 0.0), a, epsilon = 1e-12);
+        assert_approx_eq!(a.sclerp(&b, 1.0), b, epsilon = 1e-12);
+
+        // a screw around and along z
+        let start = DualQuaternion::identity();
+        let end = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), PI),
+            Vector::from([0.0, 0.0, 4.0]),
+        );
+        let half = start.sclerp(&end, 0.5);
+        let expected = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), FRAC_PI_2),
+            Vector::from([0.0, 0.0, 2.0]),
+        );
+        assert_approx_eq!(half, expected, epsilon = 1e-12);
+
+        let translation = DualQuaternion::from_translation(Vector::from([2.0, 0.0, -2.0]));
+        assert_approx_eq!(
+            start.sclerp(&translation, 0.25).translation(),
+            Vector::from([0.5, 0.0, -0.5]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn linear_blend() {
+        let a = DualQuaternion::from_translation(Vector::from([2.0, 0.0, 0.0]));
+        let b = DualQuaternion::from_translation(Vector::from([0.0, 4.0, 0.0]));
+        let blend = DualQuaternion::linear_blend(&[a, b], &[0.5, 0.5]);
+        assert_approx_eq!(
+            blend.translation(),
+            Vector::from([1.0, 2.0, 0.0]),
+            epsilon = 1e-12
+        );
+
+        // flipped signs describe the same transform and must not cancel out
+        let c = transform();
+        let blend = DualQuaternion::linear_blend(&[c, -c], &[0.3, 0.7]);
+        assert_approx_eq!(blend, c, epsilon = 1e-12);
+        assert_approx_eq!(blend.into_matrix(), SquareMatrix::from(c), epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing to blend")]
+    fn linear_blend_empty() {
+        DualQuaternion::<f64>::linear_blend(&[], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the weights cancel out")]
+    fn linear_blend_zero_weights() {
+        let c = transform();
+        DualQuaternion::linear_blend(&[c, c], &[1.0, -1.0]);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::algebra::linear::scalar::Scalar;
-use fructose::operators::{ClosedAdd, ClosedDiv, ClosedMul, ClosedSub};
-use std::fmt::{Debug, Display, Formatter};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::algebra::linear::{GenericMatrix, Matrix, Storage, VecStorage};
+use fructose::operators::{ClosedAdd, ClosedMul, ClosedSub};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -87,166 +87,108 @@ impl<T: Scalar + ClosedSub> SubAssign for DVector<T> {
     }
 }
 
-impl<T: Default + Copy> From<DMatrix<T>> for DVector<T> {
+impl<T> From<DMatrix<T>> for DVector<T> {
     fn from(rhs: DMatrix<T>) -> Self {
-        assert_eq!(rhs.size.1, 1);
-        DVector {
-            data: rhs.data[0].clone(),
-            len: rhs.size.0,
-        }
+        assert_eq!(rhs.size().1, 1);
+        DVector::new(rhs.data.into_vec())
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct DMatrix<T> {
-    pub data: Vec<Vec<T>>,
-    pub size: (usize, usize),
-}
+/// a matrix whose size is only known at runtime
+pub type DMatrix<T> = GenericMatrix<VecStorage<T>>;
 
 impl<T> DMatrix<T> {
+    /// builds a matrix out of its columns, which all need to be equally long
     pub fn new(data: Vec<Vec<T>>) -> Self {
-        let mut size = (0, 0);
-        size.1 = data.len();
-        if data.len() != 0 {
-            size.0 = data.get(0).unwrap().len()
-        }
+        let rows = data.first().map_or(0, |col| col.len());
+        let columns = data.len();
+        assert!(data.iter().all(|col| col.len() == rows));
+
+        Self::from_vec(data.into_iter().flatten().collect(), (rows, columns))
+    }
 
-        Self { data, size }
+    /// `data` holds the entries column by column
+    #[inline]
+    pub fn from_vec(data: Vec<T>, size: (usize, usize)) -> Self {
+        Self {
+            data: VecStorage::new(data, size),
+        }
     }
 }
 
 impl<T: ToString> DMatrix<T> {
     pub fn to_string_vec(&self) -> DMatrix<String> {
-        let data_str: Vec<Vec<String>> = self
-            .data
-            .iter()
-            .map(|col| col.iter().map(|val| val.to_string()).collect())
-            .collect();
-
-        DMatrix {
-            data: data_str,
-            size: self.size,
-        }
+        let data_str = self.as_slice().iter().map(|val| val.to_string()).collect();
+        DMatrix::from_vec(data_str, self.size())
     }
 }
 
-impl<T: Default> Default for DMatrix<T> {
+impl<T> Default for DMatrix<T> {
     fn default() -> Self {
         Self {
-            data: Default::default(),
-            size: Default::default(),
+            data: VecStorage::default(),
         }
     }
 }
 
 impl<T: Default + Copy> DMatrix<T> {
     pub fn default_with_size(size: (usize, usize)) -> Self {
-        let mut data = vec![vec![T::default(); size.0]; size.1];
-        Self { data, size }
+        Self::from_vec(vec![T::default(); size.0 * size.1], size)
     }
 }
 
-impl<T: Scalar + ClosedAdd> Add for DMatrix<T> {
+impl<T: Scalar + ClosedAdd + ClosedMul> Mul for DMatrix<T> {
     type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.size, rhs.size);
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[n][m] += rhs.data[n][m];
-            }
-        }
-        mat
-    }
-}
-
-impl<T: Scalar + ClosedAdd> AddAssign for DMatrix<T> {
-    fn add_assign(&mut self, rhs: Self) {
-        assert_eq!(self.size, rhs.size);
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                self.data[n][m] += rhs.data[n][m];
-            }
-        }
+    fn mul(self, rhs: Self) -> Self::Output {
+        self * &rhs
     }
 }
 
-impl<T: Scalar + ClosedSub> Sub for DMatrix<T> {
+impl<'r, T: Scalar + ClosedAdd + ClosedMul, R: Storage<Element = T>> Mul<&'r GenericMatrix<R>>
+    for DMatrix<T>
+{
     type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.size, rhs.size);
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[n][m] -= rhs.data[n][m];
-            }
-        }
+    fn mul(self, rhs: &'r GenericMatrix<R>) -> Self::Output {
+        let mut mat = Self::default_with_size((self.size().0, rhs.size().1));
+        self.mul_to(rhs, &mut mat);
         mat
     }
 }
 
-impl<T: Scalar + ClosedSub> SubAssign for DMatrix<T> {
-    fn sub_assign(&mut self, rhs: Self) {
-        assert_eq!(self.size, rhs.size);
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                self.data[n][m] -= rhs.data[n][m];
-            }
-        }
+impl<T: Scalar + ClosedAdd + ClosedMul> MulAssign for DMatrix<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
     }
 }
 
-impl<T: Scalar + ClosedAdd + ClosedMul> Mul for DMatrix<T> {
-    type Output = Self;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        assert_eq!(self.size.0, rhs.size.1);
-        let mut mat = Self::default_with_size((rhs.size.1, self.size.0));
-        for m in 0..self.size.0 {
-            for p in 0..rhs.size.1 {
-                for n in 0..self.size.1 {
-                    mat.data[p][m] += self.data[n][m] * rhs.data[p][n];
-                }
-            }
-        }
-        mat
+impl<'r, T: Scalar + ClosedAdd + ClosedMul, R: Storage<Element = T>> MulAssign<&'r GenericMatrix<R>>
+    for DMatrix<T>
+{
+    fn mul_assign(&mut self, rhs: &'r GenericMatrix<R>) {
+        let mut mat = Self::default_with_size((self.size().0, rhs.size().1));
+        self.mul_to(rhs, &mut mat);
+        *self = mat;
     }
 }
 
-impl<T: Scalar + ClosedMul> MulAssign for DMatrix<T> {
-    fn mul_assign(&mut self, rhs: Self) {
-        assert_eq!(self.size.0, rhs.size.1);
-        for m in 0..self.size.0 {
-            for p in 0..rhs.size.1 {
-                for n in 0..self.size.1 {
-                    self.data[p][m] *= rhs.data[p][n];
-                }
-            }
-        }
+impl<T: Copy, const M: usize, const N: usize> From<[[T; M]; N]> for DMatrix<T> {
+    fn from(rhs: [[T; M]; N]) -> Self {
+        Self::from_vec(rhs.iter().flatten().copied().collect(), (M, N))
     }
 }
 
-impl<T: Default + Copy, const M: usize, const N: usize> From<[[T; M]; N]> for DMatrix<T> {
-    fn from(rhs: [[T; M]; N]) -> Self {
-        let mut mat = Self::default_with_size((M, N));
-        for m in 0..M {
-            for n in 0..N {
-                mat.data[n][m] = rhs[n][m];
-            }
-        }
-        mat
+impl<T: Copy, const M: usize, const N: usize> From<Matrix<T, { M }, { N }>> for DMatrix<T> {
+    fn from(rhs: Matrix<T, { M }, { N }>) -> Self {
+        Self::from(rhs.data)
     }
 }
 
-impl<T: Default + Copy> From<DVector<T>> for DMatrix<T> {
+impl<T> From<DVector<T>> for DMatrix<T> {
     fn from(rhs: DVector<T>) -> Self {
         let len = rhs.len;
-        DMatrix {
-            data: vec![rhs.data],
-            size: (len, 0),
-        }
+        Self::from_vec(rhs.data, (len, 1))
     }
 }
 
@@ -258,135 +200,33 @@ impl<T: Scalar + ClosedAdd + ClosedMul> Mul<DVector<T>> for DMatrix<T> {
     }
 }
 
-impl<T: Scalar + ClosedMul> Mul<T> for DMatrix<T> {
-    type Output = Self;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[m][n] *= rhs
-            }
-        }
-        self
-    }
-}
-
-impl<T: Scalar + ClosedMul> MulAssign<T> for DMatrix<T> {
-    fn mul_assign(&mut self, rhs: T) {
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                self.data[m][n] *= rhs
-            }
-        }
-    }
-}
-
-impl<T: Scalar + ClosedDiv> Div<T> for DMatrix<T> {
-    type Output = Self;
-
-    fn div(self, rhs: T) -> Self::Output {
-        let mut mat = self.clone();
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                mat.data[m][n] /= rhs
-            }
-        }
-        self
-    }
-}
-
-impl<T: Scalar + ClosedDiv> DivAssign<T> for DMatrix<T> {
-    fn div_assign(&mut self, rhs: T) {
-        for m in 0..self.size.0 {
-            for n in 0..self.size.1 {
-                self.data[m][n] /= rhs
-            }
-        }
-    }
-}
-
-impl<T: Display + Copy> Display for DMatrix<T> {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut string = String::new();
-        let biggest = format!("{}", self.data.get(1).unwrap().get(1).unwrap()).len();
-        for m in 0..self.size.0 {
-            string.push_str("|");
-            for n in 0..self.size.1 {
-                if n == self.size.1 - 1 {
-                    &string.push_str(&format!("{}", self.data.get(n).unwrap().get(m).unwrap()));
-                    break;
-                }
-                let current = format!("{}", self.data.get(n).unwrap().get(m).unwrap()).len();
-
-                string.push_str(&format!("{} ", self.data.get(n).unwrap().get(m).unwrap()));
-
-                for _ in current..biggest + 1 {
-                    string.push(' ');
-                }
-            }
-            &string.push_str("|\n");
-        }
-        write!(f, "{}", string)
-    }
-}
-
-impl<T: FromStr + Default> From<&str> for DVector<T> {
+/// parses like `FromStr`, panics if `rhs` is malformed
+impl<T: FromStr> From<&str> for DVector<T> {
     fn from(rhs: &str) -> Self {
-        let data = rhs
-            .split(" ")
-            .map(|val| val.parse::<T>().unwrap_or_else(|t| <T>::default()))
-            .collect::<Vec<T>>();
-
-        let len = data.len();
-        Self { data, len }
+        rhs.parse()
+            .unwrap_or_else(|e| panic!("invalid vector: {}", e))
     }
 }
 
-impl<T: FromStr + Default> From<String> for DVector<T> {
+/// parses like `FromStr`, panics if `rhs` is malformed
+impl<T: FromStr> From<String> for DVector<T> {
     fn from(rhs: String) -> Self {
-        let data = rhs
-            .split(" ")
-            .map(|val| val.parse::<T>().unwrap_or_else(|t| <T>::default()))
-            .collect::<Vec<T>>();
-
-        let len = data.len();
-        Self { data, len }
+        Self::from(rhs.as_str())
     }
 }
 
-impl<T: FromStr + Default> From<String> for DMatrix<T> {
+/// parses like `FromStr`, panics if `rhs` is malformed
+impl<T: FromStr> From<String> for DMatrix<T> {
     fn from(rhs: String) -> Self {
-        let cols_str = rhs.split(";").collect::<Vec<&str>>();
-        let cols_t = cols_str
-            .iter()
-            .map(|str| {
-                str.split(" ")
-                    .map(|val| val.parse::<T>().unwrap_or_else(|t| <T>::default()))
-                    .collect::<Vec<T>>()
-            })
-            .collect::<Vec<Vec<T>>>();
-
-        let size = (cols_t[0].len(), cols_t.len());
-        Self { data: cols_t, size }
+        Self::from(rhs.as_str())
     }
 }
 
-impl<T: FromStr + Default> From<&str> for DMatrix<T> {
+/// parses like `FromStr`, panics if `rhs` is malformed
+impl<T: FromStr> From<&str> for DMatrix<T> {
     fn from(rhs: &str) -> Self {
-        let cols_t = rhs
-            .split(";")
-            .into_iter()
-            .map(|str| {
-                str.split(" ")
-                    .map(|val| val.parse::<T>().unwrap_or_else(|t| <T>::default()))
-                    .collect::<Vec<T>>()
-            })
-            .collect::<Vec<Vec<T>>>();
-
-        let size = (cols_t[0].len(), cols_t.len());
-        Self { data: cols_t, size }
+        rhs.parse()
+            .unwrap_or_else(|e| panic!("invalid matrix: {}", e))
     }
 }
 
@@ -414,6 +254,13 @@ mod dynamic_mat_tests {
         let mat1 = DMatrix::new(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]);
         let mat2 = DMatrix::new(vec![vec![7.0, 9.0, 11.0], vec![8.0, 10.0, 12.0]]);
         let mat3 = mat1 * mat2;
+        assert_eq!(
+            mat3,
+            DMatrix::new(vec![vec![58.0, 139.0], vec![64.0, 154.0]])
+        );
+
+        let vec = DMatrix::from([[1, 0], [0, 2]]) * DVector::new(vec![3, 4]);
+        assert_eq!(vec.data, vec![3, 8]);
     }
 
     #[test]
@@ -430,5 +277,16 @@ mod dynamic_mat_tests {
         let vec1 = DVector::<f64>::from("4 3 2");
         let vec2 = DVector::<f64>::from("-2.5 3 2");
         let vec = vec1 + vec2;
+
+        assert_eq!(
+            DMatrix::<i32>::from("1 2;3 4").as_slice(),
+            DMatrix::<i32>::from(String::from("[1 3; 2 4]")).as_slice()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_invalid_str() {
+        let _ = DMatrix::<i32>::from("1 2;3 x");
     }
 }
@@ -0,0 +1,380 @@
+use crate::algebra::linear::mat::SquareMatrix;
+use crate::algebra::linear::quaternion::Quaternion;
+use crate::algebra::linear::rotor::Rotor3;
+use crate::algebra::linear::scalar::Scalar;
+use fructose::operators::{ClosedNeg, ClosedOps};
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// the axes of the three rotations in the order they are applied
+///
+/// tait-bryan orders rotate around three different axes,
+/// proper euler orders rotate around the same axis first and last.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+/// whether the axes move with the rotated body or stay fixed in the world
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EulerFrame {
+    /// every rotation is around an axis already rotated by the previous ones
+    Intrinsic,
+    /// every rotation is around a fixed world axis
+    Extrinsic,
+}
+
+impl EulerOrder {
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    /// the axis indices, `0` is x
+    #[inline]
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// the first and the last axis are the same
+    #[inline]
+    pub const fn is_proper(self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
+
+/// three angles in radians, `angles[n]` rotates around `order.axes()[n]`
+///
+/// intrinsic `XYZ` is the matrix `X * Y * Z`, extrinsic `XYZ` is `Z * Y * X`,
+/// so every extrinsic order is the reversed intrinsic order with reversed angles.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EulerAngles<T> {
+    pub angles: [T; 3],
+    pub order: EulerOrder,
+    pub frame: EulerFrame,
+}
+
+impl<T: Copy> EulerAngles<T> {
+    #[inline]
+    pub const fn new(angles: [T; 3], order: EulerOrder, frame: EulerFrame) -> Self {
+        Self {
+            angles,
+            order,
+            frame,
+        }
+    }
+
+    /// the axes and angles of the same rotation written as intrinsic rotations
+    fn intrinsic(&self) -> ([usize; 3], [T; 3]) {
+        let [i, j, k] = self.order.axes();
+        let [a, b, c] = self.angles;
+        match self.frame {
+            EulerFrame::Intrinsic => ([i, j, k], [a, b, c]),
+            EulerFrame::Extrinsic => ([k, j, i], [c, b, a]),
+        }
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> EulerAngles<T> {
+    /// intrinsic `ZYX`, the aerospace convention
+    #[inline]
+    pub fn from_yaw_pitch_roll(yaw: T, pitch: T, roll: T) -> Self {
+        Self::new([yaw, pitch, roll], EulerOrder::ZYX, EulerFrame::Intrinsic)
+    }
+
+    /// yaw, pitch and roll of the same rotation
+    #[inline]
+    pub fn yaw_pitch_roll(&self) -> (T, T, T) {
+        let [yaw, pitch, roll] = self.to_order(EulerOrder::ZYX, EulerFrame::Intrinsic).angles;
+        (yaw, pitch, roll)
+    }
+
+    /// the same rotation in another order or frame
+    #[inline]
+    pub fn to_order(&self, order: EulerOrder, frame: EulerFrame) -> Self {
+        if order == self.order && frame == self.frame {
+            return *self;
+        }
+        Self::from_matrix(&self.into_matrix(), order, frame)
+    }
+
+    /// the middle angle aligns the first and the last axis,
+    /// so only the sum or difference of the outer angles is defined
+    #[inline]
+    pub fn is_gimbal_locked(&self) -> bool {
+        let tolerance = <T as Float>::EPSILON.sqrt();
+        let (sin, cos) = self.angles[1].sin_cos();
+        if self.order.is_proper() {
+            sin.abs() <= tolerance
+        } else {
+            cos.abs() <= tolerance
+        }
+    }
+
+    /// the angles of an orthonormal matrix with determinant 1
+    ///
+    /// the middle angle lies in `[-π/2, π/2]` for tait-bryan and in `[0, π]` for proper orders,
+    /// the others in `[-π, π]`. at gimbal lock the whole rotation around the aligned axes
+    /// goes into the outermost rotation and the innermost angle is zero,
+    /// that is the last angle for intrinsic and the first for extrinsic angles.
+    pub fn from_matrix(mat: &SquareMatrix<T, 3>, order: EulerOrder, frame: EulerFrame) -> Self {
+        let tolerance = <T as Float>::EPSILON.sqrt();
+        let m = |row: usize, col: usize| mat[[row, col]];
+
+        // extract the intrinsic angles of R = R_i(a) R_j(b) R_k(c)
+        let [i, j, k] = match frame {
+            EulerFrame::Intrinsic => order.axes(),
+            EulerFrame::Extrinsic => {
+                let [i, j, k] = order.axes();
+                [k, j, i]
+            }
+        };
+        // the sign of the permutation (i, j, k), or (i, j, other axis) for proper orders
+        let e = if (j + 3 - i) % 3 == 1 {
+            T::one()
+        } else {
+            -T::one()
+        };
+
+        let [a, b, c] = if i != k {
+            let cos = (m(i, i) * m(i, i) + m(i, j) * m(i, j)).sqrt();
+            let b = (e * m(i, k)).atan2(cos);
+            if cos <= tolerance {
+                [(e * m(k, j)).atan2(m(j, j)), b, T::zero()]
+            } else {
+                [
+                    (-e * m(j, k)).atan2(m(k, k)),
+                    b,
+                    (-e * m(i, j)).atan2(m(i, i)),
+                ]
+            }
+        } else {
+            let k = 3 - i - j;
+            let sin = (m(i, j) * m(i, j) + m(i, k) * m(i, k)).sqrt();
+            let b = sin.atan2(m(i, i));
+            if sin <= tolerance {
+                // b is 0 or π, the outer rotations add up or cancel
+                let e = if m(i, i) > T::zero() { -e } else { e };
+                [(e * m(j, k)).atan2(m(j, j)), b, T::zero()]
+            } else {
+                [m(j, i).atan2(-e * m(k, i)), b, m(i, j).atan2(e * m(i, k))]
+            }
+        };
+
+        let angles = match frame {
+            EulerFrame::Intrinsic => [a, b, c],
+            EulerFrame::Extrinsic => [c, b, a],
+        };
+        Self::new(angles, order, frame)
+    }
+
+    #[inline]
+    pub fn from_quaternion(
+        quaternion: Quaternion<T>,
+        order: EulerOrder,
+        frame: EulerFrame,
+    ) -> Self {
+        Self::from_matrix(&quaternion.normalized().into_matrix(), order, frame)
+    }
+
+    #[inline]
+    pub fn from_rotor(rotor: Rotor3<T>, order: EulerOrder, frame: EulerFrame) -> Self {
+        Self::from_quaternion(Quaternion::from(rotor), order, frame)
+    }
+
+    pub fn into_quaternion(self) -> Quaternion<T> {
+        let (axes, angles) = self.intrinsic();
+        let two = T::one() + T::one();
+        axes.iter()
+            .zip(angles.iter())
+            .fold(Quaternion::identity(), |q, (axis, angle)| {
+                let (sin, cos) = (*angle / two).sin_cos();
+                let mut rotation = Quaternion::new(T::zero(), T::zero(), T::zero(), cos);
+                rotation.as_mut_slice()[*axis] = sin;
+                q * rotation
+            })
+    }
+
+    #[inline]
+    pub fn into_matrix(self) -> SquareMatrix<T, 3> {
+        self.into_quaternion().into_matrix()
+    }
+
+    #[inline]
+    pub fn into_rotor(self) -> Rotor3<T> {
+        Rotor3::from(self.into_quaternion())
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> From<EulerAngles<T>> for SquareMatrix<T, 3> {
+    fn from(rhs: EulerAngles<T>) -> Self {
+        rhs.into_matrix()
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> From<EulerAngles<T>> for Quaternion<T> {
+    fn from(rhs: EulerAngles<T>) -> Self {
+        rhs.into_quaternion()
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> From<EulerAngles<T>> for Rotor3<T> {
+    fn from(rhs: EulerAngles<T>) -> Self {
+        rhs.into_rotor()
+    }
+}
+
+#[cfg(test)]
+mod euler_tests {
+    use crate::algebra::linear::{
+        EulerAngles, EulerFrame, EulerOrder, Quaternion, Rotor2, Rotor3, SquareMatrix, Vector,
+    };
+    use crate::assert_approx_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    const FRAMES: [EulerFrame; 2] = [EulerFrame::Intrinsic, EulerFrame::Extrinsic];
+
+    #[test]
+    fn conventions() {
+        let intrinsic = EulerAngles::new([0.3, -0.5, 1.2], EulerOrder::XYZ, EulerFrame::Intrinsic);
+        let extrinsic = EulerAngles::new([1.2, -0.5, 0.3], EulerOrder::ZYX, EulerFrame::Extrinsic);
+        assert_approx_eq!(
+            intrinsic.into_matrix(),
+            extrinsic.into_matrix(),
+            epsilon = 1e-12
+        );
+
+        let x = Quaternion::from_axis_angle(Vector::from([1.0, 0.0, 0.0]), 0.3);
+        let y = Quaternion::from_axis_angle(Vector::from([0.0, 1.0, 0.0]), -0.5);
+        let z = Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), 1.2);
+        assert_approx_eq!(intrinsic.into_quaternion(), x * y * z, epsilon = 1e-12);
+
+        let aerospace = EulerAngles::from_yaw_pitch_roll(1.1, -0.7, 0.3);
+        assert_approx_eq!(
+            aerospace.into_quaternion(),
+            Quaternion::from_euler_angles(0.3, -0.7, 1.1),
+            epsilon = 1e-12
+        );
+        let (yaw, pitch, roll) = intrinsic.yaw_pitch_roll();
+        assert_approx_eq!(
+            EulerAngles::from_yaw_pitch_roll(yaw, pitch, roll).into_matrix(),
+            intrinsic.into_matrix(),
+            epsilon = 1e-12
+        );
+
+        let vec = Vector::from([1.0, 2.0, -0.5]);
+        let rotor = Rotor3::from(intrinsic);
+        assert_approx_eq!(rotor * vec, intrinsic.into_matrix() * vec, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn round_trip() {
+        for order in EulerOrder::ALL.iter() {
+            let middle = if order.is_proper() { 1.1 } else { -0.6 };
+            for frame in FRAMES.iter() {
+                let angles = EulerAngles::new([0.4, middle, -2.5], *order, *frame);
+                let mat: SquareMatrix<f64, 3> = angles.into();
+                let back = EulerAngles::from_matrix(&mat, *order, *frame);
+                assert_approx_eq!(
+                    Vector::from(back.angles),
+                    Vector::from(angles.angles),
+                    epsilon = 1e-12
+                );
+                assert!(!back.is_gimbal_locked());
+
+                let q = Quaternion::from(angles);
+                let back = EulerAngles::from_quaternion(q, *order, *frame);
+                assert_approx_eq!(
+                    Vector::from(back.angles),
+                    Vector::from(angles.angles),
+                    epsilon = 1e-12
+                );
+                let back = EulerAngles::from_rotor(Rotor3::from(q), *order, *frame);
+                assert_approx_eq!(
+                    Vector::from(back.angles),
+                    Vector::from(angles.angles),
+                    epsilon = 1e-12
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gimbal_lock() {
+        for order in EulerOrder::ALL.iter() {
+            let middles = if order.is_proper() {
+                [0.0, PI]
+            } else {
+                [FRAC_PI_2, -FRAC_PI_2]
+            };
+            for middle in middles.iter() {
+                for frame in FRAMES.iter() {
+                    let angles = EulerAngles::new([0.4, *middle, -2.5], *order, *frame);
+                    assert!(angles.is_gimbal_locked());
+                    let back = EulerAngles::from_matrix(&angles.into_matrix(), *order, *frame);
+                    assert!(back.is_gimbal_locked());
+                    let innermost = match frame {
+                        EulerFrame::Intrinsic => back.angles[2],
+                        EulerFrame::Extrinsic => back.angles[0],
+                    };
+                    assert_eq!(innermost, 0.0);
+                    assert_approx_eq!(back.into_matrix(), angles.into_matrix(), epsilon = 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotor2_angle() {
+        for angle in [0.0, 0.5, -2.0, 3.0].iter() {
+            assert_approx_eq!(Rotor2::from_angle(*angle).angle(), *angle, epsilon = 1e-12);
+        }
+
+        // angles beyond π wrap around, and the negated rotor has the same angle
+        let rotor = Rotor2::from_angle(4.0);
+        let negated = Rotor2::new(-rotor.scalar, -rotor.bivector);
+        assert_approx_eq!(rotor.angle(), 4.0 - 2.0 * PI, epsilon = 1e-12);
+        assert_approx_eq!(negated.angle(), 4.0 - 2.0 * PI, epsilon = 1e-12);
+        assert_approx_eq!(
+            Rotor2::from_angle(-4.0).angle(),
+            2.0 * PI - 4.0,
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(Rotor2::from_angle(PI).angle().abs(), PI, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::algebra::linear::{Matrix, RowVector, Scalar, Vector};
+
+/// compile time check for the dimensions of stacked matrices
+///
+/// referencing `SUM_MATCHES` fails to compile if `A + B != SUM`
+struct StackDimensions<const A: usize, const B: usize, const SUM: usize>;
+
+impl<const A: usize, const B: usize, const SUM: usize> StackDimensions<{ A }, { B }, { SUM }> {
+    const SUM_MATCHES: () = assert!(A + B == SUM, "stacked dimensions do not add up");
+}
+
+impl<T: Scalar, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    #[inline]
+    pub fn transpose(&self) -> Matrix<T, { N }, { M }> {
+        let mut mat = Matrix::default();
+        for m in 0..M {
+            for n in 0..N {
+                mat[[n, m]] = self[[m, n]];
+            }
+        }
+        mat
+    }
+
+    #[inline]
+    pub fn row(&self, i: usize) -> RowVector<T, { N }> {
+        let mut row = RowVector::default();
+        for n in 0..N {
+            row[[0, n]] = self[[i, n]];
+        }
+        row
+    }
+
+    #[inline]
+    pub fn column(&self, j: usize) -> Vector<T, { M }> {
+        Vector::from(self.data[j])
+    }
+
+    /// returns the `R`x`C` block whose upper left entry is at row `i` and column `j`
+    #[inline]
+    pub fn fixed_block<const R: usize, const C: usize>(
+        &self,
+        i: usize,
+        j: usize,
+    ) -> Matrix<T, { R }, { C }> {
+        assert!(i + R <= M && j + C <= N, "block is out of bounds");
+        let mut block = Matrix::default();
+        for r in 0..R {
+            for c in 0..C {
+                block[[r, c]] = self[[i + r, j + c]];
+            }
+        }
+        block
+    }
+
+    /// places `rhs` to the right of `self`, `Q` has to be `N + P`
+    #[inline]
+    pub fn hstack<const P: usize, const Q: usize>(
+        &self,
+        rhs: &Matrix<T, { M }, { P }>,
+    ) -> Matrix<T, { M }, { Q }> {
+        #[allow(clippy::let_unit_value)]
+        let _ = StackDimensions::<{ N }, { P }, { Q }>::SUM_MATCHES;
+        let mut mat = Matrix::default();
+        mat.data[..N].copy_from_slice(&self.data);
+        mat.data[N..].copy_from_slice(&rhs.data);
+        mat
+    }
+
+    /// places `rhs` below `self`, `Q` has to be `M + P`
+    #[inline]
+    pub fn vstack<const P: usize, const Q: usize>(
+        &self,
+        rhs: &Matrix<T, { P }, { N }>,
+    ) -> Matrix<T, { Q }, { N }> {
+        #[allow(clippy::let_unit_value)]
+        let _ = StackDimensions::<{ M }, { P }, { Q }>::SUM_MATCHES;
+        let mut mat = Matrix::<T, { Q }, { N }>::default();
+        for n in 0..N {
+            mat.data[n][..M].copy_from_slice(&self.data[n]);
+            mat.data[n][M..].copy_from_slice(&rhs.data[n]);
+        }
+        mat
+    }
+}
+
+#[cfg(test)]
+mod block_tests {
+    use crate::algebra::linear::{Matrix, RowVector, Vector};
+
+    fn mat() -> Matrix<i32, 2, 3> {
+        Matrix::new([[1, 4], [2, 5], [3, 6]])
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!(mat().transpose(), Matrix::new([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(mat().transpose().transpose(), mat());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        assert_eq!(mat().row(1), RowVector::new([[4], [5], [6]]));
+        assert_eq!(mat().column(2), Vector::from([3, 6]));
+        assert_eq!(
+            mat().fixed_block::<2, 2>(0, 1),
+            Matrix::new([[2, 5], [3, 6]])
+        );
+        assert_eq!(mat().fixed_block::<1, 1>(1, 2), Matrix::new([[6]]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn block_out_of_bounds() {
+        mat().fixed_block::<2, 2>(1, 0);
+    }
+
+    #[test]
+    fn stack() {
+        let right = Matrix::<i32, 2, 1>::new([[7, 8]]);
+        let wide: Matrix<i32, 2, 4> = mat().hstack(&right);
+        assert_eq!(wide, Matrix::new([[1, 4], [2, 5], [3, 6], [7, 8]]));
+
+        let below = Matrix::<i32, 1, 3>::new([[7], [8], [9]]);
+        let tall: Matrix<i32, 3, 3> = mat().vstack(&below);
+        assert_eq!(tall, Matrix::new([[1, 4, 7], [2, 5, 8], [3, 6, 9]]));
+    }
+}
@@ -0,0 +1,271 @@
+use crate::algebra::linear::mat::error::DecompositionError;
+use crate::algebra::linear::{GenericMatrix, Scalar, SquareMatrix, Storage, StorageMut, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// Cholesky decomposition of a symmetric positive definite matrix: `A = L * L^T`
+///
+/// only the lower triangle of `A` is read.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    pub l: SquareMatrix<T, { N }>,
+}
+
+/// LDL^T decomposition of a symmetric positive semidefinite matrix: `A = L * D * L^T`
+///
+/// `L` is unit lower triangular and `d` holds the diagonal of `D`.
+/// only the lower triangle of `A` is read.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LDLT<T, const N: usize> {
+    pub l: SquareMatrix<T, { N }>,
+    pub d: Vector<T, { N }>,
+}
+
+/// tolerance for vanishing pivots, scaled by the biggest diagonal entry and the dimension
+fn tolerance<T: Scalar + Real + ClosedOps + Float, S: Storage<Element = T>>(
+    matrix: &GenericMatrix<S>,
+) -> T {
+    let n = matrix.size().0;
+    let mut scale = T::zero();
+    for i in 0..n {
+        if matrix[[i, i]].abs() > scale {
+            scale = matrix[[i, i]].abs();
+        }
+    }
+    let mut tolerance = T::zero();
+    for _ in 0..n {
+        tolerance += scale * <T as Float>::EPSILON;
+    }
+    tolerance
+}
+
+/// writes the cholesky factor of the square `matrix` into the lower triangle of `l`
+///
+/// the upper triangle of `l` is left untouched.
+pub(crate) fn factor<T, S, L>(
+    matrix: &GenericMatrix<S>,
+    l: &mut GenericMatrix<L>,
+) -> Result<(), DecompositionError>
+where
+    T: Scalar + Real + ClosedOps + Float,
+    S: Storage<Element = T>,
+    L: StorageMut<Element = T>,
+{
+    let n = matrix.size().0;
+    assert_eq!(matrix.size(), (n, n));
+    assert_eq!(l.size(), (n, n));
+    let tolerance = tolerance(matrix);
+
+    for j in 0..n {
+        let mut diag = matrix[[j, j]];
+        for k in 0..j {
+            diag -= l[[j, k]] * l[[j, k]];
+        }
+        if diag <= tolerance {
+            return Err(DecompositionError::NotPositiveDefinite);
+        }
+        let diag = diag.sqrt();
+        l[[j, j]] = diag;
+
+        for i in j + 1..n {
+            let mut sum = matrix[[i, j]];
+            for k in 0..j {
+                sum -= l[[i, k]] * l[[j, k]];
+            }
+            l[[i, j]] = sum / diag;
+        }
+    }
+
+    Ok(())
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> Cholesky<T, { N }> {
+    pub fn new(matrix: SquareMatrix<T, { N }>) -> Result<Self, DecompositionError> {
+        let mut l = SquareMatrix::<T, { N }>::default();
+        factor(&matrix, &mut l)?;
+        Ok(Self { l })
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for i in 0..N {
+            det *= self.l[[i, i]] * self.l[[i, i]];
+        }
+        det
+    }
+
+    /// solves `A * x = b`
+    pub fn solve(&self, b: &Vector<T, { N }>) -> Vector<T, { N }> {
+        let mut x = *b;
+        for i in 0..N {
+            let mut sum = x[i];
+            for k in 0..i {
+                sum -= self.l[[i, k]] * x[k];
+            }
+            x[i] = sum / self.l[[i, i]];
+        }
+
+        for i in (0..N).rev() {
+            let mut sum = x[i];
+            for k in i + 1..N {
+                sum -= self.l[[k, i]] * x[k];
+            }
+            x[i] = sum / self.l[[i, i]];
+        }
+        x
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> LDLT<T, { N }> {
+    pub fn new(matrix: SquareMatrix<T, { N }>) -> Result<Self, DecompositionError> {
+        let tolerance = tolerance(&matrix);
+        let mut l = SquareMatrix::<T, { N }>::mul_identity();
+        let mut d = Vector::<T, { N }>::default();
+
+        for j in 0..N {
+            let mut diag = matrix[[j, j]];
+            for k in 0..j {
+                diag -= l[[j, k]] * l[[j, k]] * d[k];
+            }
+            if diag < -tolerance {
+                return Err(DecompositionError::NotPositiveSemidefinite);
+            }
+            let singular = diag <= tolerance;
+            d[j] = if singular { T::zero() } else { diag };
+
+            for i in j + 1..N {
+                let mut sum = matrix[[i, j]];
+                for k in 0..j {
+                    sum -= l[[i, k]] * l[[j, k]] * d[k];
+                }
+                if singular {
+                    // a semidefinite matrix has a zero column wherever the pivot vanishes
+                    if sum.abs() > tolerance {
+                        return Err(DecompositionError::NotPositiveSemidefinite);
+                    }
+                    l[[i, j]] = T::zero();
+                } else {
+                    l[[i, j]] = sum / diag;
+                }
+            }
+        }
+
+        Ok(Self { l, d })
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let mut det = T::one();
+        for i in 0..N {
+            det *= self.d[i];
+        }
+        det
+    }
+
+    #[inline]
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.d[i].is_zero())
+    }
+
+    /// solves `A * x = b`, returns `None` if `A` is singular
+    pub fn solve(&self, b: &Vector<T, { N }>) -> Option<Vector<T, { N }>> {
+        if self.is_singular() {
+            return None;
+        }
+
+        let mut x = *b;
+        for i in 0..N {
+            let mut sum = x[i];
+            for k in 0..i {
+                sum -= self.l[[i, k]] * x[k];
+            }
+            x[i] = sum;
+        }
+
+        for i in 0..N {
+            x[i] /= self.d[i];
+        }
+
+        for i in (0..N).rev() {
+            let mut sum = x[i];
+            for k in i + 1..N {
+                sum -= self.l[[k, i]] * x[k];
+            }
+            x[i] = sum;
+        }
+        Some(x)
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> SquareMatrix<T, { N }> {
+    #[inline]
+    pub fn cholesky(&self) -> Result<Cholesky<T, { N }>, DecompositionError> {
+        Cholesky::new(*self)
+    }
+
+    #[inline]
+    pub fn ldlt(&self) -> Result<LDLT<T, { N }>, DecompositionError> {
+        LDLT::new(*self)
+    }
+}
+
+#[cfg(test)]
+mod cholesky_tests {
+    use crate::algebra::linear::mat::error::DecompositionError;
+    use crate::algebra::linear::{SquareMatrix, Vector};
+
+    fn spd() -> SquareMatrix<f64, 3> {
+        SquareMatrix::new([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ])
+    }
+
+    #[test]
+    fn cholesky() {
+        let mat = spd();
+        let cholesky = mat.cholesky().unwrap();
+        assert_eq!(
+            cholesky.l,
+            SquareMatrix::new([[2.0, 6.0, -8.0], [0.0, 1.0, 5.0], [0.0, 0.0, 3.0]])
+        );
+        assert!((cholesky.determinant() - 36.0).abs() < 1e-10);
+
+        let b = Vector::from([1.0, 2.0, 3.0]);
+        let diff = mat * cholesky.solve(&b) - b;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-10));
+
+        let indefinite = SquareMatrix::<f64, 2>::new([[1.0, 2.0], [2.0, 1.0]]);
+        assert_eq!(
+            indefinite.cholesky(),
+            Err(DecompositionError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn ldlt() {
+        let mat = spd();
+        let ldlt = mat.ldlt().unwrap();
+        assert_eq!(ldlt.d, Vector::from([4.0, 1.0, 9.0]));
+        assert!((ldlt.determinant() - 36.0).abs() < 1e-10);
+
+        let b = Vector::from([1.0, 2.0, 3.0]);
+        let diff = mat * ldlt.solve(&b).unwrap() - b;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-10));
+
+        let semidefinite = SquareMatrix::<f64, 2>::new([[1.0, 1.0], [1.0, 1.0]]);
+        let ldlt = semidefinite.ldlt().unwrap();
+        assert!(ldlt.is_singular());
+        assert!(ldlt.solve(&Vector::from([1.0, 0.0])).is_none());
+        assert_eq!(ldlt.determinant(), 0.0);
+
+        let indefinite = SquareMatrix::<f64, 2>::new([[1.0, 2.0], [2.0, 1.0]]);
+        assert_eq!(
+            indefinite.ldlt(),
+            Err(DecompositionError::NotPositiveSemidefinite)
+        );
+    }
+}
@@ -0,0 +1,841 @@
+//! factorizations of matrices whose size is only known at runtime
+//!
+//! they reuse the kernels of the fixed size decompositions and share the `Decomposition` api,
+//! so a system is solved the same way whichever factorization fits the matrix. A pivot, diagonal
+//! entry, eigenvalue or singular value counts as zero if it is not bigger than the biggest one of
+//! them times the dimension times machine epsilon.
+use crate::algebra::linear::mat::eigen::DEFAULT_MAX_SWEEPS;
+use crate::algebra::linear::mat::error::DecompositionError;
+use crate::algebra::linear::mat::{cholesky, eigen, lu, qr, svd};
+use crate::algebra::linear::{DMatrix, DVector, GenericMatrix, Scalar, Storage};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// the operations every decomposition of a `DMatrix` supports
+pub trait Decomposition<T: Scalar + Real + ClosedOps + Float> {
+    /// `(rows, columns)` of the decomposed matrix
+    fn shape(&self) -> (usize, usize);
+
+    /// solves `A * X = B` for every column of `B`
+    ///
+    /// with more rows than columns `X` is the least squares solution. Fails if `B` does not have
+    /// as many rows as `A` or if the solution is not unique.
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError>;
+
+    /// solves `A * x = b`
+    #[inline]
+    fn solve_vector(&self, b: &DVector<T>) -> Result<DVector<T>, DecompositionError> {
+        self.solve(&DMatrix::from(b.clone())).map(DVector::from)
+    }
+
+    /// fails if the matrix is not square
+    fn determinant(&self) -> Result<T, DecompositionError>;
+
+    /// `A^-1`, fails if the matrix is not square or singular
+    fn inverse(&self) -> Result<DMatrix<T>, DecompositionError> {
+        let (rows, columns) = self.shape();
+        if rows != columns {
+            return Err(DecompositionError::NotSquare { rows, columns });
+        }
+        self.solve(&identity(rows))
+    }
+
+    /// the number of linearly independent columns
+    fn rank(&self) -> usize;
+}
+
+fn identity<T: Scalar + Real + ClosedOps + Float>(n: usize) -> DMatrix<T> {
+    let mut mat = DMatrix::from_element((n, n), T::zero());
+    (0..n).for_each(|i| mat[[i, i]] = T::one());
+    mat
+}
+
+fn check_square<S: Storage>(matrix: &GenericMatrix<S>) -> Result<usize, DecompositionError> {
+    match matrix.size() {
+        (rows, columns) if rows == columns => Ok(rows),
+        (rows, columns) => Err(DecompositionError::NotSquare { rows, columns }),
+    }
+}
+
+fn check_rows<T>(expected: usize, b: &DMatrix<T>) -> Result<(), DecompositionError> {
+    let found = b.size().0;
+    if found == expected {
+        Ok(())
+    } else {
+        Err(DecompositionError::DimensionMismatch { expected, found })
+    }
+}
+
+/// `scale * eps * n`, magnitudes not bigger than this count as zero
+fn tolerance<T: Scalar + Real + ClosedOps + Float>(scale: T, n: usize) -> T {
+    let mut tolerance = T::zero();
+    for _ in 0..n {
+        tolerance += scale * <T as Float>::EPSILON;
+    }
+    tolerance
+}
+
+/// the number of entries of `values` whose magnitude is not negligible compared to the biggest one
+fn count_nonzero<T: Scalar + Real + ClosedOps + Float>(
+    values: impl Iterator<Item = T> + Clone,
+    n: usize,
+) -> usize {
+    let mut scale = T::zero();
+    values.clone().for_each(|e| {
+        if e.abs() > scale {
+            scale = e.abs()
+        }
+    });
+    let tolerance = tolerance(scale, n);
+    values.filter(|e| e.abs() > tolerance).count()
+}
+
+/// 1 for even permutations, -1 for odd ones
+fn permutation_sign<T: Scalar + Real + ClosedOps + Float>(permutation: &[usize]) -> T {
+    let mut visited = vec![false; permutation.len()];
+    let mut sign = T::one();
+    for start in 0..permutation.len() {
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            i = permutation[i];
+            if i != start {
+                sign = -sign;
+            }
+        }
+    }
+    sign
+}
+
+/// LU decomposition with complete pivoting: `P * A * Q = L * U`
+///
+/// `L` is unit lower triangular and `U` upper triangular. Entry `[i, j]` of `P * A * Q` is entry
+/// `[permutation[i], columns[j]]` of `A`, `sign` is the sign of both permutations together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DLU<T> {
+    pub l: DMatrix<T>,
+    pub u: DMatrix<T>,
+    pub permutation: Vec<usize>,
+    pub columns: Vec<usize>,
+    pub sign: T,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DLU<T> {
+    pub fn new(matrix: DMatrix<T>) -> Result<Self, DecompositionError> {
+        let n = check_square(&matrix)?;
+        let mut u = matrix;
+        let mut l = identity(n);
+        let mut permutation = (0..n).collect::<Vec<_>>();
+        let mut columns = permutation.clone();
+        let sign = lu::eliminate(&mut u, &mut l, &mut permutation, Some(&mut columns));
+
+        Ok(Self {
+            l,
+            u,
+            permutation,
+            columns,
+            sign,
+        })
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> Decomposition<T> for DLU<T> {
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        (self.columns.len(), self.columns.len())
+    }
+
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError> {
+        let n = self.columns.len();
+        check_rows(n, b)?;
+        if self.rank() < n {
+            return Err(DecompositionError::Singular);
+        }
+
+        let mut y = DMatrix::from_element(b.size(), T::zero());
+        let mut x = DMatrix::from_element(b.size(), T::zero());
+        for c in 0..b.size().1 {
+            for i in 0..n {
+                let mut sum = b[[self.permutation[i], c]];
+                for j in 0..i {
+                    sum -= self.l[[i, j]] * y[[j, c]];
+                }
+                y[[i, c]] = sum;
+            }
+            for i in (0..n).rev() {
+                let mut sum = y[[i, c]];
+                for j in i + 1..n {
+                    sum -= self.u[[i, j]] * y[[j, c]];
+                }
+                y[[i, c]] = sum / self.u[[i, i]];
+            }
+            (0..n).for_each(|i| x[[self.columns[i], c]] = y[[i, c]]);
+        }
+        Ok(x)
+    }
+
+    #[inline]
+    fn determinant(&self) -> Result<T, DecompositionError> {
+        let mut det = self.sign;
+        (0..self.columns.len()).for_each(|k| det *= self.u[[k, k]]);
+        Ok(det)
+    }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        let n = self.columns.len();
+        count_nonzero((0..n).map(|k| self.u[[k, k]]), n)
+    }
+}
+
+/// QR decomposition with column pivoting: `A * P = Q * R`
+///
+/// `Q` is orthogonal and `R` upper triangular with a diagonal decreasing in magnitude.
+/// Column `j` of `A * P` is column `columns[j]` of `A`, `sign` is `det(Q)` times the sign of `P`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DQR<T> {
+    pub q: DMatrix<T>,
+    pub r: DMatrix<T>,
+    pub columns: Vec<usize>,
+    pub sign: T,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DQR<T> {
+    pub fn new(matrix: DMatrix<T>) -> Self {
+        let (rows, columns) = matrix.size();
+        let mut q = identity(rows);
+        let mut r = matrix;
+        let mut v = vec![T::zero(); rows];
+        let mut permutation = (0..columns).collect::<Vec<_>>();
+        let det = qr::householder(&mut r, &mut q, &mut v, Some(&mut permutation));
+
+        Self {
+            q,
+            r,
+            sign: det * permutation_sign(&permutation),
+            columns: permutation,
+        }
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> Decomposition<T> for DQR<T> {
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.r.size()
+    }
+
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError> {
+        let (rows, columns) = self.shape();
+        check_rows(rows, b)?;
+        if rows < columns {
+            return Err(DecompositionError::Underdetermined { rows, columns });
+        }
+        if self.rank() < columns {
+            return Err(DecompositionError::Singular);
+        }
+
+        let mut y = DMatrix::from_element((columns, b.size().1), T::zero());
+        let mut x = DMatrix::from_element((columns, b.size().1), T::zero());
+        for c in 0..b.size().1 {
+            // Q^T * b, only the first `columns` entries are needed
+            for j in 0..columns {
+                let mut dot = T::zero();
+                for i in 0..rows {
+                    dot += self.q[[i, j]] * b[[i, c]];
+                }
+                y[[j, c]] = dot;
+            }
+            for i in (0..columns).rev() {
+                let mut sum = y[[i, c]];
+                for j in i + 1..columns {
+                    sum -= self.r[[i, j]] * y[[j, c]];
+                }
+                y[[i, c]] = sum / self.r[[i, i]];
+            }
+            (0..columns).for_each(|i| x[[self.columns[i], c]] = y[[i, c]]);
+        }
+        Ok(x)
+    }
+
+    fn determinant(&self) -> Result<T, DecompositionError> {
+        let n = check_square(&self.r)?;
+        let mut det = self.sign;
+        (0..n).for_each(|k| det *= self.r[[k, k]]);
+        Ok(det)
+    }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        let (rows, columns) = self.shape();
+        let diagonal = (0..rows.min(columns)).map(|k| self.r[[k, k]]);
+        count_nonzero(diagonal, rows.max(columns))
+    }
+}
+
+/// Cholesky decomposition of a symmetric positive definite matrix: `A = L * L^T`
+///
+/// only the lower triangle of `A` is read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DCholesky<T> {
+    pub l: DMatrix<T>,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DCholesky<T> {
+    pub fn new(matrix: DMatrix<T>) -> Result<Self, DecompositionError> {
+        let n = check_square(&matrix)?;
+        let mut l = DMatrix::from_element((n, n), T::zero());
+        cholesky::factor(&matrix, &mut l)?;
+        Ok(Self { l })
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> Decomposition<T> for DCholesky<T> {
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.l.size()
+    }
+
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError> {
+        let n = self.l.size().0;
+        check_rows(n, b)?;
+
+        let mut x = b.clone();
+        for c in 0..b.size().1 {
+            for i in 0..n {
+                let mut sum = x[[i, c]];
+                for k in 0..i {
+                    sum -= self.l[[i, k]] * x[[k, c]];
+                }
+                x[[i, c]] = sum / self.l[[i, i]];
+            }
+            for i in (0..n).rev() {
+                let mut sum = x[[i, c]];
+                for k in i + 1..n {
+                    sum -= self.l[[k, i]] * x[[k, c]];
+                }
+                x[[i, c]] = sum / self.l[[i, i]];
+            }
+        }
+        Ok(x)
+    }
+
+    #[inline]
+    fn determinant(&self) -> Result<T, DecompositionError> {
+        let mut det = T::one();
+        (0..self.l.size().0).for_each(|i| det *= self.l[[i, i]] * self.l[[i, i]]);
+        Ok(det)
+    }
+
+    /// positive definite matrices always have full rank
+    #[inline]
+    fn rank(&self) -> usize {
+        self.l.size().0
+    }
+}
+
+/// eigendecomposition of a symmetric matrix: `A = V * diag(eigenvalues) * V^T`
+///
+/// eigenvalues are sorted in ascending order and column `i` of `eigenvectors`
+/// is the normalized eigenvector of `eigenvalues[i]`.
+/// `sweeps` is the number of jacobi sweeps it took to converge.
+#[derive(Debug, Clone)]
+pub struct DSymmetricEigen<T> {
+    pub eigenvalues: DVector<T>,
+    pub eigenvectors: DMatrix<T>,
+    pub sweeps: usize,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DSymmetricEigen<T> {
+    /// cyclic jacobi eigenvalue algorithm, only the upper triangle of `A` is read
+    ///
+    /// iterates until the norm of the off-diagonal part is below `tolerance` relative to
+    /// the norm of `A`, fails if that did not happen within `max_sweeps` sweeps
+    pub fn new(
+        matrix: DMatrix<T>,
+        tolerance: T,
+        max_sweeps: usize,
+    ) -> Result<Self, DecompositionError> {
+        match Self::jacobi(matrix, tolerance, max_sweeps)? {
+            (eigen, true) => Ok(eigen),
+            (_, false) => Err(DecompositionError::NotConverged),
+        }
+    }
+
+    /// returns the decomposition after the last sweep and whether it converged
+    fn jacobi(
+        matrix: DMatrix<T>,
+        tolerance: T,
+        max_sweeps: usize,
+    ) -> Result<(Self, bool), DecompositionError> {
+        let n = check_square(&matrix)?;
+        let mut a = matrix;
+        let mut v = identity(n);
+        let mut eigenvalues = vec![T::zero(); n];
+        let (sweeps, converged) =
+            eigen::jacobi(&mut a, &mut v, &mut eigenvalues, tolerance, max_sweeps);
+
+        let eigen = Self {
+            eigenvalues: DVector::new(eigenvalues),
+            eigenvectors: v,
+            sweeps,
+        };
+        Ok((eigen, converged))
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> Decomposition<T> for DSymmetricEigen<T> {
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.eigenvectors.size()
+    }
+
+    /// `X = V * diag(eigenvalues)^-1 * V^T * B`
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError> {
+        let n = self.eigenvalues.len;
+        check_rows(n, b)?;
+        if self.rank() < n {
+            return Err(DecompositionError::Singular);
+        }
+
+        let mut x = DMatrix::from_element(b.size(), T::zero());
+        for c in 0..b.size().1 {
+            for k in 0..n {
+                let mut dot = T::zero();
+                for i in 0..n {
+                    dot += self.eigenvectors[[i, k]] * b[[i, c]];
+                }
+                let factor = dot / self.eigenvalues.data[k];
+                for i in 0..n {
+                    x[[i, c]] += factor * self.eigenvectors[[i, k]];
+                }
+            }
+        }
+        Ok(x)
+    }
+
+    #[inline]
+    fn determinant(&self) -> Result<T, DecompositionError> {
+        let mut det = T::one();
+        self.eigenvalues.data.iter().for_each(|e| det *= *e);
+        Ok(det)
+    }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        let n = self.eigenvalues.len;
+        count_nonzero(self.eigenvalues.data.iter().copied(), n)
+    }
+}
+
+/// singular value decomposition: `A = U * Σ * V^T`
+///
+/// `U` and `V^T` are orthogonal, `sigma` is the rectangular diagonal matrix `Σ`
+/// with the singular values sorted in descending order on its diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DSVD<T> {
+    pub u: DMatrix<T>,
+    pub sigma: DMatrix<T>,
+    pub v_t: DMatrix<T>,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DSVD<T> {
+    pub fn new(matrix: DMatrix<T>) -> Self {
+        let (rows, columns) = matrix.size();
+        let mut sigma = DMatrix::from_element((rows, columns), T::zero());
+        if rows >= columns {
+            let (u, values, v) = Self::jacobi(matrix);
+            (0..columns).for_each(|i| sigma[[i, i]] = values[i]);
+            Self {
+                u,
+                sigma,
+                v_t: v.transpose_view().clone_owned(),
+            }
+        } else {
+            // A^T = U' * Σ' * V'^T  =>  A = V' * Σ'^T * U'^T
+            let (u, values, v) = Self::jacobi(matrix.transpose_view().clone_owned());
+            (0..rows).for_each(|i| sigma[[i, i]] = values[i]);
+            Self {
+                u: v,
+                sigma,
+                v_t: u.transpose_view().clone_owned(),
+            }
+        }
+    }
+
+    /// the full `U`, the singular values and `V` of a matrix with at least as many rows as columns
+    fn jacobi(matrix: DMatrix<T>) -> (DMatrix<T>, Vec<T>, DMatrix<T>) {
+        let (rows, columns) = matrix.size();
+        let mut w = matrix;
+        let mut u = DMatrix::from_element((rows, rows), T::zero());
+        let mut values = vec![T::zero(); columns];
+        let mut v = identity(columns);
+        svd::jacobi(&mut w, &mut u, &mut values, &mut v);
+        (u, values, v)
+    }
+
+    /// the number of singular values, `min(rows, columns)`
+    #[inline]
+    pub fn len(&self) -> usize {
+        let (rows, columns) = self.sigma.size();
+        rows.min(columns)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns the `i`th biggest singular value
+    #[inline]
+    pub fn singular_value(&self, i: usize) -> T {
+        self.sigma[[i, i]]
+    }
+
+    /// Moore-Penrose pseudo-inverse `V * Σ^+ * U^T`, singular values not bigger than `eps` are treated as zero
+    pub fn pseudo_inverse(&self, eps: T) -> DMatrix<T> {
+        let (rows, columns) = self.sigma.size();
+        let mut inv = DMatrix::from_element((columns, rows), T::zero());
+        for k in 0..self.len() {
+            let value = self.singular_value(k);
+            if value <= eps {
+                continue;
+            }
+            for i in 0..columns {
+                let v = self.v_t[[k, i]] / value;
+                for j in 0..rows {
+                    inv[[i, j]] += v * self.u[[j, k]];
+                }
+            }
+        }
+        inv
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> Decomposition<T> for DSVD<T> {
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.sigma.size()
+    }
+
+    fn solve(&self, b: &DMatrix<T>) -> Result<DMatrix<T>, DecompositionError> {
+        let (rows, columns) = self.shape();
+        check_rows(rows, b)?;
+        if rows < columns {
+            return Err(DecompositionError::Underdetermined { rows, columns });
+        }
+        if self.rank() < columns {
+            return Err(DecompositionError::Singular);
+        }
+        Ok(self.pseudo_inverse(T::zero()) * b)
+    }
+
+    /// the determinants of `U` and `V^T` are `±1`, their signs are taken from an LU decomposition
+    fn determinant(&self) -> Result<T, DecompositionError> {
+        let n = check_square(&self.sigma)?;
+        let mut det = T::one();
+        for orthogonal in [&self.u, &self.v_t].iter() {
+            if DLU::new((*orthogonal).clone())?.determinant()? < T::zero() {
+                det = -det;
+            }
+        }
+        (0..n).for_each(|i| det *= self.singular_value(i));
+        Ok(det)
+    }
+
+    #[inline]
+    fn rank(&self) -> usize {
+        let (rows, columns) = self.shape();
+        let values = (0..self.len()).map(|i| self.singular_value(i));
+        count_nonzero(values, rows.max(columns))
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float> DMatrix<T> {
+    /// fails if the matrix is not square
+    #[inline]
+    pub fn lu(&self) -> Result<DLU<T>, DecompositionError> {
+        DLU::new(self.clone())
+    }
+
+    #[inline]
+    pub fn qr(&self) -> DQR<T> {
+        DQR::new(self.clone())
+    }
+
+    /// fails if the matrix is not square or not positive definite
+    #[inline]
+    pub fn cholesky(&self) -> Result<DCholesky<T>, DecompositionError> {
+        DCholesky::new(self.clone())
+    }
+
+    /// eigendecomposition of a symmetric matrix to machine precision, fails if it is not square
+    ///
+    /// also fails if it did not converge within `DEFAULT_MAX_SWEEPS` sweeps
+    #[inline]
+    pub fn symmetric_eigen(&self) -> Result<DSymmetricEigen<T>, DecompositionError> {
+        DSymmetricEigen::new(self.clone(), <T as Float>::EPSILON, DEFAULT_MAX_SWEEPS)
+    }
+
+    #[inline]
+    pub fn svd(&self) -> DSVD<T> {
+        DSVD::new(self.clone())
+    }
+
+    /// fails if the matrix is not square
+    #[inline]
+    pub fn determinant(&self) -> Result<T, DecompositionError> {
+        self.lu()?.determinant()
+    }
+
+    /// fails if the matrix is not square or singular
+    #[inline]
+    pub fn inverse(&self) -> Result<Self, DecompositionError> {
+        self.lu()?.inverse()
+    }
+
+    /// solves `A * X = B`, in the least squares sense if there are more rows than columns
+    pub fn solve(&self, b: &Self) -> Result<Self, DecompositionError> {
+        let (rows, columns) = self.size();
+        if rows == columns {
+            self.lu()?.solve(b)
+        } else {
+            self.qr().solve(b)
+        }
+    }
+
+    /// the number of linearly independent columns
+    #[inline]
+    pub fn rank(&self) -> usize {
+        self.svd().rank()
+    }
+}
+
+#[cfg(test)]
+mod decomposition_tests {
+    use super::identity;
+    use crate::algebra::linear::{
+        DMatrix, DSymmetricEigen, DVector, Decomposition, DecompositionError, SquareMatrix,
+    };
+    use crate::assert_approx_eq;
+
+    fn fixed() -> SquareMatrix<f64, 4> {
+        SquareMatrix::new([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ])
+    }
+
+    fn square() -> DMatrix<f64> {
+        DMatrix::from(fixed())
+    }
+
+    #[test]
+    fn lu() {
+        let mat = square();
+        let lu = mat.lu().unwrap();
+        let mut pa = DMatrix::from_element((4, 4), 0.0);
+        for i in 0..4 {
+            for j in 0..4 {
+                pa[[i, j]] = mat[[lu.permutation[i], lu.columns[j]]];
+            }
+        }
+        assert_approx_eq!(pa, lu.l.clone() * &lu.u, epsilon = 1e-10);
+
+        assert!((lu.determinant().unwrap() - fixed().determinant()).abs() < 1e-10);
+        assert_approx_eq!(
+            mat.clone() * mat.inverse().unwrap(),
+            identity(4),
+            epsilon = 1e-10
+        );
+        assert_eq!(lu.rank(), 4);
+
+        let b = DVector::new(vec![1.0, 2.0, 3.0, 4.0]);
+        let x = lu.solve_vector(&b).unwrap();
+        assert_approx_eq!(DMatrix::from(mat * x), DMatrix::from(b), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn qr() {
+        let tall: DMatrix<f64> = DMatrix::new(vec![
+            vec![1.0, 2.0, -1.0, 3.0, 0.0],
+            vec![4.0, 0.0, 2.0, 1.0, 1.0],
+            vec![-2.0, 5.0, 1.0, 0.0, 2.0],
+        ]);
+        let qr = tall.qr();
+        assert_approx_eq!(qr.q.transpose_view() * &qr.q, identity(5), epsilon = 1e-10);
+        let mut permuted = DMatrix::from_element((5, 3), 0.0);
+        for j in 0..3 {
+            permuted
+                .columns_mut(j..=j)
+                .copy_from(&tall.columns(qr.columns[j]..=qr.columns[j]));
+        }
+        assert_approx_eq!(permuted, qr.q.clone() * &qr.r, epsilon = 1e-10);
+        assert!(qr.r[[0, 0]].abs() >= qr.r[[1, 1]].abs());
+        assert_eq!(qr.rank(), 3);
+
+        // the residual of a least squares solution is orthogonal to the columns
+        let b = DMatrix::new(vec![vec![1.0, 0.0, 2.0, -1.0, 3.0]]);
+        let x = qr.solve(&b).unwrap();
+        let residual = tall.clone() * &x - b;
+        assert_approx_eq!(
+            tall.transpose_view() * &residual,
+            DMatrix::from_element((3, 1), 0.0),
+            epsilon = 1e-10
+        );
+
+        let mat = square();
+        let det = mat.qr().determinant().unwrap();
+        assert!((det - mat.determinant().unwrap()).abs() < 1e-10);
+        assert_eq!(
+            tall.qr().determinant(),
+            Err(DecompositionError::NotSquare {
+                rows: 5,
+                columns: 3
+            })
+        );
+    }
+
+    #[test]
+    fn cholesky() {
+        let mat: DMatrix<f64> = DMatrix::new(vec![
+            vec![4.0, 2.0, -2.0],
+            vec![2.0, 10.0, 2.0],
+            vec![-2.0, 2.0, 6.0],
+        ]);
+        let cholesky = mat.cholesky().unwrap();
+        assert_approx_eq!(
+            cholesky.l.clone() * &cholesky.l.transpose_view(),
+            mat,
+            epsilon = 1e-10
+        );
+        assert!((cholesky.determinant().unwrap() - mat.determinant().unwrap()).abs() < 1e-10);
+        assert_approx_eq!(
+            mat.clone() * cholesky.inverse().unwrap(),
+            identity(3),
+            epsilon = 1e-10
+        );
+        assert_eq!(
+            square().cholesky().unwrap_err(),
+            DecompositionError::NotPositiveDefinite
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen() {
+        let mat = square();
+        let eigen = mat.symmetric_eigen().unwrap();
+        let fixed = fixed().symmetric_eigen();
+        for i in 0..4 {
+            assert!((eigen.eigenvalues.data[i] - fixed.eigenvalues[i]).abs() < 1e-10);
+        }
+
+        let mut diagonal = DMatrix::from_element((4, 4), 0.0);
+        (0..4).for_each(|i| diagonal[[i, i]] = eigen.eigenvalues.data[i]);
+        let v = &eigen.eigenvectors;
+        assert_approx_eq!(
+            v.clone() * &diagonal * &v.transpose_view(),
+            mat,
+            epsilon = 1e-10
+        );
+        assert!((eigen.determinant().unwrap() - mat.determinant().unwrap()).abs() < 1e-10);
+        assert_approx_eq!(
+            mat.clone() * eigen.inverse().unwrap(),
+            identity(4),
+            epsilon = 1e-10
+        );
+
+        assert_eq!(
+            DSymmetricEigen::new(mat, 1e-15, 0).unwrap_err(),
+            DecompositionError::NotConverged
+        );
+    }
+
+    #[test]
+    fn svd() {
+        let wide: DMatrix<f64> =
+            DMatrix::new(vec![vec![3.0, 2.0], vec![2.0, 3.0], vec![2.0, -2.0]]);
+        let svd = wide.svd();
+        assert_approx_eq!(svd.u.clone() * &svd.sigma * &svd.v_t, wide, epsilon = 1e-10);
+        assert!((svd.singular_value(0) - 5.0).abs() < 1e-12);
+        assert!((svd.singular_value(1) - 3.0).abs() < 1e-12);
+        assert_eq!(
+            svd.solve(&DMatrix::from_element((2, 1), 1.0)).unwrap_err(),
+            DecompositionError::Underdetermined {
+                rows: 2,
+                columns: 3
+            }
+        );
+
+        let mat = square();
+        let svd = mat.svd();
+        assert!((svd.determinant().unwrap() - mat.determinant().unwrap()).abs() < 1e-10);
+        assert_approx_eq!(
+            mat.clone() * svd.inverse().unwrap(),
+            identity(4),
+            epsilon = 1e-10
+        );
+        assert_approx_eq!(
+            svd.pseudo_inverse(1e-10),
+            mat.inverse().unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mat: DMatrix<f64> = DMatrix::new(vec![
+            vec![1.0, 2.0, 0.0],
+            vec![2.0, 4.0, 1.0],
+            vec![3.0, 6.0, 1.0],
+        ]);
+        let b = DMatrix::from_element((3, 1), 1.0);
+        assert_eq!(mat.rank(), 2);
+        assert_eq!(mat.lu().unwrap().rank(), 2);
+        assert_eq!(mat.qr().rank(), 2);
+        assert_eq!(mat.svd().rank(), 2);
+        assert!(mat.determinant().unwrap().abs() < 1e-12);
+        assert_eq!(mat.solve(&b), Err(DecompositionError::Singular));
+        assert_eq!(mat.qr().solve(&b), Err(DecompositionError::Singular));
+        assert_eq!(mat.svd().inverse(), Err(DecompositionError::Singular));
+
+        let symmetric = mat.clone() * &mat.transpose_view();
+        let eigen = symmetric.symmetric_eigen().unwrap();
+        assert_eq!(eigen.rank(), 2);
+        assert_eq!(eigen.inverse().unwrap_err(), DecompositionError::Singular);
+    }
+
+    #[test]
+    fn errors() {
+        let tall = DMatrix::from_element((3, 2), 1.0);
+        let not_square = DecompositionError::NotSquare {
+            rows: 3,
+            columns: 2,
+        };
+        assert_eq!(tall.lu().unwrap_err(), not_square);
+        assert_eq!(tall.cholesky().unwrap_err(), not_square);
+        assert_eq!(tall.symmetric_eigen().unwrap_err(), not_square);
+        assert_eq!(tall.determinant(), Err(not_square));
+        assert_eq!(tall.svd().inverse(), Err(not_square));
+
+        let b = DMatrix::from_element((2, 1), 1.0);
+        assert_eq!(
+            square().lu().unwrap().solve(&b),
+            Err(DecompositionError::DimensionMismatch {
+                expected: 4,
+                found: 2
+            })
+        );
+        assert_eq!(format!("{}", not_square), "matrix is not square, it is 3x2");
+    }
+
+    #[test]
+    fn mul_assign() {
+        let mut mat = DMatrix::new(vec![vec![1.0, 3.0], vec![2.0, 4.0]]);
+        let rhs = DMatrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+        mat *= rhs;
+        assert_eq!(mat, DMatrix::new(vec![vec![2.0, 4.0], vec![1.0, 3.0]]));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::algebra::linear::{GenericMatrix, Scalar, SquareMatrix, StorageMut, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// maximum number of jacobi sweeps used by `SquareMatrix::symmetric_eigen`
+pub const DEFAULT_MAX_SWEEPS: usize = 64;
+
+/// eigendecomposition of a symmetric matrix: `A = V * diag(eigenvalues) * V^T`
+///
+/// eigenvalues are sorted in ascending order and column `i` of `eigenvectors`
+/// is the normalized eigenvector of `eigenvalues[i]`.
+/// `sweeps` is the number of jacobi sweeps it took to converge.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SymmetricEigen<T, const N: usize> {
+    pub eigenvalues: Vector<T, { N }>,
+    pub eigenvectors: SquareMatrix<T, { N }>,
+    pub sweeps: usize,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> SymmetricEigen<T, { N }> {
+    /// cyclic jacobi eigenvalue algorithm, only the upper triangle of `A` is read
+    ///
+    /// iterates until the norm of the off-diagonal part is below `tolerance` relative to
+    /// the norm of `A`, returns `None` if that did not happen within `max_sweeps` sweeps
+    pub fn new(matrix: SquareMatrix<T, { N }>, tolerance: T, max_sweeps: usize) -> Option<Self> {
+        match Self::jacobi(matrix, tolerance, max_sweeps) {
+            (eigen, true) => Some(eigen),
+            (_, false) => None,
+        }
+    }
+
+    /// returns the decomposition after the last sweep and whether it converged
+    fn jacobi(matrix: SquareMatrix<T, { N }>, tolerance: T, max_sweeps: usize) -> (Self, bool) {
+        let mut a = matrix;
+        let mut v = SquareMatrix::<T, { N }>::mul_identity();
+        let mut eigenvalues = Vector::<T, { N }>::default();
+        let (sweeps, converged) = jacobi(
+            &mut a,
+            &mut v,
+            eigenvalues.as_slice_mut(),
+            tolerance,
+            max_sweeps,
+        );
+
+        let eigen = Self {
+            eigenvalues,
+            eigenvectors: v,
+            sweeps,
+        };
+        (eigen, converged)
+    }
+}
+
+/// cyclic jacobi sweeps on the symmetric matrix whose upper triangle is stored in `a`
+///
+/// `v` has to start out as the identity and receives the eigenvectors, `eigenvalues` the
+/// eigenvalues in ascending order. Returns the number of sweeps and whether they converged.
+pub(crate) fn jacobi<T, S>(
+    a: &mut GenericMatrix<S>,
+    v: &mut GenericMatrix<S>,
+    eigenvalues: &mut [T],
+    tolerance: T,
+    max_sweeps: usize,
+) -> (usize, bool)
+where
+    T: Scalar + Real + ClosedOps + Float,
+    S: StorageMut<Element = T>,
+{
+    let n = eigenvalues.len();
+    assert_eq!(a.size(), (n, n));
+    assert_eq!(v.size(), (n, n));
+    for j in 0..n {
+        for i in j + 1..n {
+            a[[i, j]] = a[[j, i]];
+        }
+    }
+
+    let mut norm = T::zero();
+    a.iter().for_each(|e| norm += *e * *e);
+    let threshold = tolerance * tolerance * norm;
+
+    let mut sweeps = 0;
+    let converged = loop {
+        let mut off = T::zero();
+        for p in 0..n {
+            for q in p + 1..n {
+                off += a[[p, q]] * a[[p, q]];
+            }
+        }
+        if off + off <= threshold {
+            break true;
+        }
+        if sweeps == max_sweeps {
+            break false;
+        }
+        sweeps += 1;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[[p, q]].is_zero() {
+                    continue;
+                }
+                rotate(a, v, p, q);
+            }
+        }
+    };
+
+    for i in 0..n {
+        eigenvalues[i] = a[[i, i]];
+    }
+
+    // selection sort, swapping the eigenvectors along
+    for i in 0..n {
+        let mut min = i;
+        for j in i + 1..n {
+            if eigenvalues[j] < eigenvalues[min] {
+                min = j;
+            }
+        }
+        if min != i {
+            eigenvalues.swap(i, min);
+            v.swap_columns(i, min);
+        }
+    }
+
+    (sweeps, converged)
+}
+
+/// applies the jacobi rotation zeroing `a[p, q]`: `A = J^T * A * J` and `V = V * J`
+fn rotate<T, S>(a: &mut GenericMatrix<S>, v: &mut GenericMatrix<S>, p: usize, q: usize)
+where
+    T: Scalar + Real + ClosedOps + Float,
+    S: StorageMut<Element = T>,
+{
+    let n = a.size().0;
+    let two = T::one() + T::one();
+    let theta = (a[[q, q]] - a[[p, p]]) / (two * a[[p, q]]);
+    let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+    let t = if theta < T::zero() { -t } else { t };
+    let c = T::one() / (t * t + T::one()).sqrt();
+    let s = t * c;
+
+    for k in 0..n {
+        let (akp, akq) = (a[[k, p]], a[[k, q]]);
+        a[[k, p]] = c * akp - s * akq;
+        a[[k, q]] = s * akp + c * akq;
+    }
+    for k in 0..n {
+        let (apk, aqk) = (a[[p, k]], a[[q, k]]);
+        a[[p, k]] = c * apk - s * aqk;
+        a[[q, k]] = s * apk + c * aqk;
+    }
+    for k in 0..n {
+        let (vkp, vkq) = (v[[k, p]], v[[k, q]]);
+        v[[k, p]] = c * vkp - s * vkq;
+        v[[k, q]] = s * vkp + c * vkq;
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> SquareMatrix<T, { N }> {
+    /// eigendecomposition of a symmetric matrix to machine precision
+    ///
+    /// if it did not converge within `DEFAULT_MAX_SWEEPS` the last sweep is returned
+    #[inline]
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, { N }> {
+        SymmetricEigen::jacobi(*self, <T as Float>::EPSILON, DEFAULT_MAX_SWEEPS).0
+    }
+
+    #[inline]
+    pub fn try_symmetric_eigen(
+        &self,
+        tolerance: T,
+        max_sweeps: usize,
+    ) -> Option<SymmetricEigen<T, { N }>> {
+        SymmetricEigen::new(*self, tolerance, max_sweeps)
+    }
+}
+
+#[cfg(test)]
+mod eigen_tests {
+    use crate::algebra::linear::SquareMatrix;
+
+    #[test]
+    fn symmetric() {
+        let mat = SquareMatrix::<f64, 4>::new([
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0],
+        ]);
+        let eigen = mat.symmetric_eigen();
+
+        for i in 0..3 {
+            assert!(eigen.eigenvalues[i] <= eigen.eigenvalues[i + 1]);
+        }
+
+        for i in 0..4 {
+            let vec = eigen.eigenvectors.column(i);
+            assert!((vec.dot(vec) - 1.0).abs() < 1e-12);
+
+            let mut diff = mat * vec;
+            for k in 0..4 {
+                diff[k] -= eigen.eigenvalues[i] * vec[k];
+            }
+            assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-10));
+        }
+
+        let mut trace = 0.0;
+        (0..4).for_each(|i| trace += eigen.eigenvalues[i]);
+        assert!((trace - 8.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn convergence_controls() {
+        let mat = SquareMatrix::<f64, 3>::new([[2.0, 1.0, 0.0], [1.0, 2.0, 1.0], [0.0, 1.0, 2.0]]);
+        assert!(mat.try_symmetric_eigen(1e-15, 0).is_none());
+
+        let eigen = mat.try_symmetric_eigen(1e-6, 32).unwrap();
+        assert!(eigen.sweeps > 0);
+        let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+        for (i, e) in expected.iter().enumerate() {
+            assert!((eigen.eigenvalues[i] - e).abs() < 1e-6);
+        }
+
+        let diagonal = SquareMatrix::<f64, 3>::mul_identity();
+        assert_eq!(diagonal.try_symmetric_eigen(0.0, 0).unwrap().sweeps, 0);
+    }
+}
@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DecompositionError {
+    NotPositiveDefinite,
+    NotPositiveSemidefinite,
+    /// the decomposition or operation needs a square matrix
+    NotSquare {
+        rows: usize,
+        columns: usize,
+    },
+    /// the right hand side has `found` rows instead of `expected`
+    DimensionMismatch {
+        expected: usize,
+        found: usize,
+    },
+    /// the solution is not unique because the matrix does not have full rank
+    Singular,
+    /// fewer equations than unknowns, the solution is not unique
+    Underdetermined {
+        rows: usize,
+        columns: usize,
+    },
+    /// an iterative algorithm did not converge within its maximum number of iterations
+    NotConverged,
+}
+
+impl Display for DecompositionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecompositionError::NotPositiveDefinite => {
+                write!(f, "matrix is not positive definite")
+            }
+            DecompositionError::NotPositiveSemidefinite => {
+                write!(f, "matrix is not positive semidefinite")
+            }
+            DecompositionError::NotSquare { rows, columns } => {
+                write!(f, "matrix is not square, it is {}x{}", rows, columns)
+            }
+            DecompositionError::DimensionMismatch { expected, found } => {
+                write!(f, "expected {} rows, found {}", expected, found)
+            }
+            DecompositionError::Singular => write!(f, "matrix is singular"),
+            DecompositionError::Underdetermined { rows, columns } => write!(
+                f,
+                "{}x{} system has fewer equations than unknowns",
+                rows, columns
+            ),
+            DecompositionError::NotConverged => write!(f, "decomposition did not converge"),
+        }
+    }
+}
+
+impl Error for DecompositionError {}
@@ -0,0 +1,255 @@
+use crate::algebra::linear::{Scalar, SquareMatrix};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> SquareMatrix<T, { N }> {
+    /// returns the inverse of the matrix or `None` if the matrix is singular
+    ///
+    /// matrices up to 4x4 are inverted in closed form using their adjugate,
+    /// bigger matrices use Gauss-Jordan elimination with partial pivoting
+    pub fn try_inverse(&self) -> Option<Self> {
+        match N {
+            0 => Some(*self),
+            1 => {
+                if self[[0, 0]].is_zero() {
+                    None
+                } else {
+                    Some(Self::broadcast(T::one() / self[[0, 0]]))
+                }
+            }
+            2 => self.inverse_2x2(),
+            3 => self.inverse_3x3(),
+            4 => self.inverse_4x4(),
+            _ => self.inverse_gauss_jordan(),
+        }
+    }
+
+    /// the biggest absolute value of all entries, used to scale the singularity tolerance
+    fn max_abs(&self) -> T {
+        let mut scale = T::zero();
+        self.as_slice().iter().for_each(|e| {
+            if e.abs() > scale {
+                scale = e.abs()
+            }
+        });
+        scale
+    }
+
+    fn is_singular_determinant(&self, det: T) -> bool {
+        let scale = self.max_abs();
+        let mut tolerance = <T as Float>::EPSILON;
+        for _ in 0..N {
+            tolerance *= scale;
+        }
+        det.abs() <= tolerance
+    }
+
+    fn inverse_2x2(&self) -> Option<Self> {
+        let a = |r: usize, c: usize| self[[r, c]];
+        let det = a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0);
+        if self.is_singular_determinant(det) {
+            return None;
+        }
+
+        let mut inv = Self::default();
+        inv[[0, 0]] = a(1, 1) / det;
+        inv[[0, 1]] = -a(0, 1) / det;
+        inv[[1, 0]] = -a(1, 0) / det;
+        inv[[1, 1]] = a(0, 0) / det;
+        Some(inv)
+    }
+
+    fn inverse_3x3(&self) -> Option<Self> {
+        let a = |r: usize, c: usize| self[[r, c]];
+        let b00 = a(1, 1) * a(2, 2) - a(1, 2) * a(2, 1);
+        let b01 = a(0, 2) * a(2, 1) - a(0, 1) * a(2, 2);
+        let b02 = a(0, 1) * a(1, 2) - a(0, 2) * a(1, 1);
+        let b10 = a(1, 2) * a(2, 0) - a(1, 0) * a(2, 2);
+        let b11 = a(0, 0) * a(2, 2) - a(0, 2) * a(2, 0);
+        let b12 = a(0, 2) * a(1, 0) - a(0, 0) * a(1, 2);
+        let b20 = a(1, 0) * a(2, 1) - a(1, 1) * a(2, 0);
+        let b21 = a(0, 1) * a(2, 0) - a(0, 0) * a(2, 1);
+        let b22 = a(0, 0) * a(1, 1) - a(0, 1) * a(1, 0);
+
+        let det = a(0, 0) * b00 + a(0, 1) * b10 + a(0, 2) * b20;
+        if self.is_singular_determinant(det) {
+            return None;
+        }
+
+        let mut inv = Self::default();
+        let adjugate = [[b00, b01, b02], [b10, b11, b12], [b20, b21, b22]];
+        for (r, row) in adjugate.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                inv[[r, c]] = *value / det;
+            }
+        }
+        Some(inv)
+    }
+
+    fn inverse_4x4(&self) -> Option<Self> {
+        let a = |r: usize, c: usize| self[[r, c]];
+        // 2x2 determinants of the upper two rows (s) and the lower two rows (c)
+        let s0 = a(0, 0) * a(1, 1) - a(1, 0) * a(0, 1);
+        let s1 = a(0, 0) * a(1, 2) - a(1, 0) * a(0, 2);
+        let s2 = a(0, 0) * a(1, 3) - a(1, 0) * a(0, 3);
+        let s3 = a(0, 1) * a(1, 2) - a(1, 1) * a(0, 2);
+        let s4 = a(0, 1) * a(1, 3) - a(1, 1) * a(0, 3);
+        let s5 = a(0, 2) * a(1, 3) - a(1, 2) * a(0, 3);
+
+        let c5 = a(2, 2) * a(3, 3) - a(3, 2) * a(2, 3);
+        let c4 = a(2, 1) * a(3, 3) - a(3, 1) * a(2, 3);
+        let c3 = a(2, 1) * a(3, 2) - a(3, 1) * a(2, 2);
+        let c2 = a(2, 0) * a(3, 3) - a(3, 0) * a(2, 3);
+        let c1 = a(2, 0) * a(3, 2) - a(3, 0) * a(2, 2);
+        let c0 = a(2, 0) * a(3, 1) - a(3, 0) * a(2, 1);
+
+        let det = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if self.is_singular_determinant(det) {
+            return None;
+        }
+
+        let adjugate = [
+            [
+                a(1, 1) * c5 - a(1, 2) * c4 + a(1, 3) * c3,
+                -a(0, 1) * c5 + a(0, 2) * c4 - a(0, 3) * c3,
+                a(3, 1) * s5 - a(3, 2) * s4 + a(3, 3) * s3,
+                -a(2, 1) * s5 + a(2, 2) * s4 - a(2, 3) * s3,
+            ],
+            [
+                -a(1, 0) * c5 + a(1, 2) * c2 - a(1, 3) * c1,
+                a(0, 0) * c5 - a(0, 2) * c2 + a(0, 3) * c1,
+                -a(3, 0) * s5 + a(3, 2) * s2 - a(3, 3) * s1,
+                a(2, 0) * s5 - a(2, 2) * s2 + a(2, 3) * s1,
+            ],
+            [
+                a(1, 0) * c4 - a(1, 1) * c2 + a(1, 3) * c0,
+                -a(0, 0) * c4 + a(0, 1) * c2 - a(0, 3) * c0,
+                a(3, 0) * s4 - a(3, 1) * s2 + a(3, 3) * s0,
+                -a(2, 0) * s4 + a(2, 1) * s2 - a(2, 3) * s0,
+            ],
+            [
+                -a(1, 0) * c3 + a(1, 1) * c1 - a(1, 2) * c0,
+                a(0, 0) * c3 - a(0, 1) * c1 + a(0, 2) * c0,
+                -a(3, 0) * s3 + a(3, 1) * s1 - a(3, 2) * s0,
+                a(2, 0) * s3 - a(2, 1) * s1 + a(2, 2) * s0,
+            ],
+        ];
+
+        let mut inv = Self::default();
+        for (r, row) in adjugate.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                inv[[r, c]] = *value / det;
+            }
+        }
+        Some(inv)
+    }
+
+    fn inverse_gauss_jordan(&self) -> Option<Self> {
+        let tolerance = self.max_abs() * <T as Float>::EPSILON;
+        let mut mat = *self;
+        let mut inv = Self::mul_identity();
+
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if mat[[i, k]].abs() > mat[[pivot, k]].abs() {
+                    pivot = i;
+                }
+            }
+
+            if mat[[pivot, k]].abs() <= tolerance {
+                return None;
+            }
+
+            if pivot != k {
+                for j in 0..N {
+                    let tmp = mat[[k, j]];
+                    mat[[k, j]] = mat[[pivot, j]];
+                    mat[[pivot, j]] = tmp;
+
+                    let tmp = inv[[k, j]];
+                    inv[[k, j]] = inv[[pivot, j]];
+                    inv[[pivot, j]] = tmp;
+                }
+            }
+
+            let diag = mat[[k, k]];
+            for j in 0..N {
+                mat[[k, j]] /= diag;
+                inv[[k, j]] /= diag;
+            }
+
+            for i in 0..N {
+                if i == k {
+                    continue;
+                }
+                let factor = mat[[i, k]];
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in 0..N {
+                    let (m, v) = (mat[[k, j]], inv[[k, j]]);
+                    mat[[i, j]] -= factor * m;
+                    inv[[i, j]] -= factor * v;
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
+
+#[cfg(test)]
+mod inverse_tests {
+    use crate::algebra::linear::SquareMatrix;
+    use fructose::operators::Multiplicative;
+    use fructose::properties::general::Invertible;
+
+    fn assert_identity<const N: usize>(mat: SquareMatrix<f64, N>) {
+        let identity = SquareMatrix::<f64, N>::mul_identity();
+        let diff = mat - identity;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-10));
+    }
+
+    #[test]
+    fn closed_forms() {
+        let mat2 = SquareMatrix::<f64, 2>::new([[4.0, 2.0], [7.0, 6.0]]);
+        assert_identity(mat2 * mat2.try_inverse().unwrap());
+
+        let mat3 = SquareMatrix::<f64, 3>::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert_identity(mat3 * mat3.try_inverse().unwrap());
+
+        let mat4 = SquareMatrix::<f64, 4>::new([
+            [2.0, 4.0, -2.0, 1.0],
+            [1.0, 3.0, 5.0, -4.0],
+            [7.0, -1.0, 0.0, 2.0],
+            [3.0, 2.0, 6.0, 8.0],
+        ]);
+        assert_identity(mat4 * mat4.try_inverse().unwrap());
+        assert_identity(mat4.try_inverse().unwrap() * mat4);
+    }
+
+    #[test]
+    fn gauss_jordan() {
+        let mut mat = SquareMatrix::<f64, 6>::default();
+        for r in 0..6 {
+            for c in 0..6 {
+                mat[[r, c]] = 1.0 / (1.0 + r as f64 + c as f64) + if r == c { 2.0 } else { 0.0 };
+            }
+        }
+        // force a pivot swap
+        mat.data.swap(0, 3);
+        assert_identity(mat * <SquareMatrix<f64, 6> as Invertible<Multiplicative>>::inverse(&mat));
+    }
+
+    #[test]
+    fn singular() {
+        let mat3 = SquareMatrix::<f64, 3>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+        assert!(mat3.try_inverse().is_none());
+
+        let mut mat5 = SquareMatrix::<f64, 5>::mul_identity();
+        mat5.data[4] = mat5.data[2];
+        assert!(mat5.try_inverse().is_none());
+    }
+}
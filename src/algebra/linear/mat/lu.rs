@@ -0,0 +1,240 @@
+use crate::algebra::linear::{GenericMatrix, Scalar, SquareMatrix, StorageMut, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// LU decomposition with partial pivoting: `P * A = L * U`
+///
+/// `L` is unit lower triangular, `U` is upper triangular and row `i` of `P * A`
+/// is row `permutation[i]` of `A`. `sign` is the sign of the permutation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LU<T, const N: usize> {
+    pub l: SquareMatrix<T, { N }>,
+    pub u: SquareMatrix<T, { N }>,
+    pub permutation: [usize; N],
+    pub sign: T,
+}
+
+/// eliminates `u` in place, returns the sign of the row and column permutations
+///
+/// the multipliers are written below the diagonal of `l`. `permutation` receives the row swaps,
+/// `columns` the column swaps if the pivot is searched in the whole remaining block instead of
+/// only the current column. Both have to start out as the identity permutation.
+pub(crate) fn eliminate<T, S>(
+    u: &mut GenericMatrix<S>,
+    l: &mut GenericMatrix<S>,
+    permutation: &mut [usize],
+    mut columns: Option<&mut [usize]>,
+) -> T
+where
+    T: Scalar + Real + ClosedOps + Float,
+    S: StorageMut<Element = T>,
+{
+    let n = permutation.len();
+    assert_eq!(u.size(), (n, n));
+    assert_eq!(l.size(), (n, n));
+    let mut sign = T::one();
+
+    for k in 0..n {
+        let (mut pivot, mut pivot_column) = (k, k);
+        let searched = if columns.is_some() { k..n } else { k..k + 1 };
+        for j in searched {
+            for i in k..n {
+                if u[[i, j]].abs() > u[[pivot, pivot_column]].abs() {
+                    pivot = i;
+                    pivot_column = j;
+                }
+            }
+        }
+
+        if let Some(columns) = columns.as_deref_mut() {
+            if pivot_column != k {
+                u.swap_columns(k, pivot_column);
+                columns.swap(k, pivot_column);
+                sign = -sign;
+            }
+        }
+
+        if pivot != k {
+            u.swap_rows(k, pivot);
+            // only the already computed multipliers are swapped
+            for j in 0..k {
+                let tmp = l[[k, j]];
+                l[[k, j]] = l[[pivot, j]];
+                l[[pivot, j]] = tmp;
+            }
+            permutation.swap(k, pivot);
+            sign = -sign;
+        }
+
+        // the whole column below the diagonal is zero, there is nothing to eliminate
+        if u[[k, k]].is_zero() {
+            continue;
+        }
+
+        for i in k + 1..n {
+            let factor = u[[i, k]] / u[[k, k]];
+            l[[i, k]] = factor;
+            u[[i, k]] = T::zero();
+            for j in k + 1..n {
+                let value = u[[k, j]];
+                u[[i, j]] -= factor * value;
+            }
+        }
+    }
+
+    sign
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> LU<T, { N }> {
+    pub fn new(matrix: SquareMatrix<T, { N }>) -> Self {
+        let mut u = matrix;
+        let mut l = SquareMatrix::<T, { N }>::mul_identity();
+        let mut permutation = [0; N];
+        permutation.iter_mut().enumerate().for_each(|(i, p)| *p = i);
+        let sign = eliminate(&mut u, &mut l, &mut permutation, None);
+
+        Self {
+            l,
+            u,
+            permutation,
+            sign,
+        }
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let mut det = self.sign;
+        for k in 0..N {
+            det *= self.u[[k, k]];
+        }
+        det
+    }
+
+    /// a matrix is considered singular if a pivot of `U` vanishes relative to the biggest entry of `U`
+    pub fn is_singular(&self) -> bool {
+        let mut scale = T::zero();
+        self.u.data.iter().for_each(|col| {
+            col.iter().for_each(|e| {
+                if e.abs() > scale {
+                    scale = e.abs()
+                }
+            })
+        });
+        let tolerance = scale * <T as Float>::EPSILON;
+
+        (0..N).any(|k| self.u[[k, k]].abs() <= tolerance)
+    }
+
+    /// solves `A * x = b`, returns `None` if `A` is singular
+    pub fn solve(&self, b: &Vector<T, { N }>) -> Option<Vector<T, { N }>> {
+        if self.is_singular() {
+            return None;
+        }
+
+        let mut x = Vector::<T, { N }>::default();
+        for i in 0..N {
+            let mut sum = b[self.permutation[i]];
+            for j in 0..i {
+                sum -= self.l[[i, j]] * x[j];
+            }
+            x[i] = sum;
+        }
+
+        for i in (0..N).rev() {
+            let mut sum = x[i];
+            for j in i + 1..N {
+                sum -= self.u[[i, j]] * x[j];
+            }
+            x[i] = sum / self.u[[i, i]];
+        }
+
+        Some(x)
+    }
+
+    /// returns the permutation as a matrix `P` with `P * A = L * U`
+    pub fn permutation_matrix(&self) -> SquareMatrix<T, { N }> {
+        let mut p = SquareMatrix::<T, { N }>::broadcast(T::zero());
+        for (i, j) in self.permutation.iter().enumerate() {
+            p[[i, *j]] = T::one();
+        }
+        p
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const N: usize> SquareMatrix<T, { N }> {
+    #[inline]
+    pub fn lu(&self) -> LU<T, { N }> {
+        LU::new(*self)
+    }
+
+    /// the product of the pivots of `lu()`, for every size
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.lu().determinant()
+    }
+}
+
+#[cfg(test)]
+mod lu_tests {
+    use crate::algebra::linear::{DMatrix, SquareMatrix, Vector};
+
+    #[test]
+    fn reconstruct() {
+        let mat = SquareMatrix::<f64, 4>::new([
+            [2.0, 4.0, -2.0, 1.0],
+            [1.0, 3.0, 5.0, -4.0],
+            [7.0, -1.0, 0.0, 2.0],
+            [3.0, 2.0, 6.0, 8.0],
+        ]);
+        let lu = mat.lu();
+        let diff = lu.permutation_matrix() * mat - lu.l * lu.u;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn determinant() {
+        let mat = SquareMatrix::<f64, 3>::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert!((mat.determinant() - 6.0).abs() < 1e-12);
+
+        let mut diagonal = SquareMatrix::<f64, 6>::mul_identity();
+        (0..6).for_each(|i| diagonal[[i, i]] = 2.0);
+        let mut swapped = diagonal;
+        swapped.data.swap(0, 5);
+        assert!((diagonal.determinant() - 64.0).abs() < 1e-12);
+        assert!((swapped.determinant() + 64.0).abs() < 1e-12);
+        assert!((DMatrix::from(swapped).determinant().unwrap() + 64.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn tiny_pivot() {
+        // eliminating without pivoting on the leading entry loses the trailing ones
+        let mat = SquareMatrix::<f64, 5>::new([
+            [1e-14, 1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0, 5.0],
+            [2.0, 3.0, 5.0, 7.0, 11.0],
+            [1.0, 4.0, 9.0, 16.0, 25.0],
+            [3.0, 1.0, 4.0, 1.0, 5.0],
+        ]);
+        assert!((mat.determinant() - 18.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn solve() {
+        let mat = SquareMatrix::<f64, 3>::new([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        let b = Vector::from([1.0, 2.0, 3.0]);
+        let x = mat.lu().solve(&b).unwrap();
+        let diff = mat * x - b;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn singular() {
+        let mat = SquareMatrix::<f64, 3>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+        let lu = mat.lu();
+        assert!(lu.is_singular());
+        assert!(lu.solve(&Vector::from([1.0, 0.0, 0.0])).is_none());
+        assert!(mat.determinant().abs() < 1e-12);
+        assert!(lu.determinant().abs() < 1e-12);
+    }
+}
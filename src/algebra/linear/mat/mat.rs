@@ -1,10 +1,14 @@
-use crate::algebra::linear::{Scalar, Vector};
+use crate::algebra::linear::{
+    ArrayStorage, DMatrix, OwnedStorage, Scalar, SliceStorage, SliceStorageMut, Storage,
+    StorageMut, Vector,
+};
 use fructose::algebra::lattice::Lattice;
 use fructose::operators::{
     ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedOps, ClosedRem, ClosedSub,
 };
 use fructose::properties::helpers::identity::{One, Zero};
 use fructose::properties::helpers::sign::Signed;
+use fructose::specific::complex::Real;
 use std::alloc::Layout;
 use std::fmt::{Display, Formatter};
 use std::ops::{
@@ -15,10 +19,17 @@ use std::str::FromStr;
 
 pub type SquareMatrix<T, const N: usize> = Matrix<T, { N }, { N }>;
 
+/// a matrix with `M` rows and `N` columns whose entries are stored inline
+pub type Matrix<T, const M: usize, const N: usize> = GenericMatrix<ArrayStorage<T, { M }, { N }>>;
+
+/// a matrix over any `Storage`
+///
+/// `Matrix` and `DMatrix` are the array and heap backed versions, everything that only needs
+/// the entries and the shape is implemented once for all of them.
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialEq)]
-pub struct Matrix<T, const M: usize, const N: usize> {
-    pub data: [[T; M]; N],
+pub struct GenericMatrix<S> {
+    pub data: S,
 }
 
 impl<T: Default + Copy, const M: usize, const N: usize> Default for Matrix<T, { M }, { N }> {
@@ -30,79 +41,277 @@ impl<T: Default + Copy, const M: usize, const N: usize> Default for Matrix<T, {
     }
 }
 
-impl<T: Display, const M: usize, const N: usize> Display for Matrix<T, { M }, { N }> {
+impl<T: Display, S: Storage<Element = T>> Display for GenericMatrix<S> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (rows, columns) = self.size();
         let mut string = String::new();
-        for m in 0..M {
-            &string.push_str("|");
-            for n in 0..N {
-                if n == N - 1 {
-                    &string.push_str(&format!("{}", self[[m, n]]));
+        for m in 0..rows {
+            string.push('|');
+            for n in 0..columns {
+                if n == columns - 1 {
+                    string.push_str(&format!("{}", self[[m, n]]));
                     break;
                 }
-                &string.push_str(&format!("{} ", self[[m, n]]));
+                string.push_str(&format!("{} ", self[[m, n]]));
             }
-            &string.push_str("|\n");
+            string.push_str("|\n");
         }
         write!(f, "{}", string)
     }
 }
 
-impl<T, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+impl<S: Storage> GenericMatrix<S> {
+    /// `(rows, columns)`
     #[inline]
+    pub fn size(&self) -> (usize, usize) {
+        self.data.shape()
+    }
 
-    pub const fn new(data: [[T; M]; N]) -> Self {
-        Self { data }
+    #[inline]
+    pub fn len(&self) -> usize {
+        let (rows, columns) = self.size();
+        rows * columns
     }
 
     #[inline]
-    pub const fn len(&self) -> usize {
-        M * N
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
+    /// `(row stride, column stride)` of the entries in `as_slice`
     #[inline]
-    pub const fn size(&self) -> (usize, usize) {
-        (M, N)
+    pub fn strides(&self) -> (usize, usize) {
+        self.data.strides()
     }
 
+    /// whether `as_slice` holds exactly the entries, column by column
     #[inline]
-    pub fn layout() -> Layout {
-        Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<[T; M]>())
-            .unwrap()
+    pub fn is_contiguous(&self) -> bool {
+        self.data.is_contiguous()
     }
 
+    /// the buffer behind the matrix, entry `[m, n]` is at `m * strides.0 + n * strides.1`
+    ///
+    /// for owned matrices this is every entry, column by column.
     #[inline]
-    pub fn as_array(&self) -> &[T; N] {
-        use std::convert::TryInto;
-        self.as_slice().try_into().unwrap()
+    pub fn as_slice(&self) -> &[S::Element] {
+        self.data.as_slice()
     }
 
     #[inline]
-    pub fn as_array_mut(&mut self) -> &mut [T; N] {
-        use std::convert::TryInto;
-        self.as_slice_mut().try_into().unwrap()
+    pub fn as_ptr(&self) -> *const S::Element {
+        self.as_slice().as_ptr()
+    }
+
+    /// all entries, column by column
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &S::Element> + '_ {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        let slice = self.as_slice();
+        (0..columns)
+            .flat_map(move |n| (0..rows).map(move |m| &slice[m * row_stride + n * column_stride]))
+    }
+
+    /// the transpose, borrowing the entries instead of moving them
+    #[inline]
+    pub fn transpose_view(&self) -> GenericMatrix<SliceStorage<'_, S::Element>> {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        GenericMatrix {
+            data: SliceStorage::with_strides(
+                self.as_slice(),
+                (columns, rows),
+                (column_stride, row_stride),
+            ),
+        }
+    }
+
+    /// copies the entries into a contiguous `DMatrix`
+    #[inline]
+    pub fn clone_owned(&self) -> DMatrix<S::Element>
+    where
+        S::Element: Clone,
+    {
+        DMatrix::from_vec(self.iter().cloned().collect(), self.size())
+    }
+}
+
+impl<S: StorageMut> GenericMatrix<S> {
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [S::Element] {
+        self.data.as_mut_slice()
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut S::Element {
+        self.as_slice_mut().as_mut_ptr()
+    }
+
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        assert!(a < rows && b < rows, "row index out of bounds");
+        for n in 0..columns {
+            self.as_slice_mut().swap(
+                a * row_stride + n * column_stride,
+                b * row_stride + n * column_stride,
+            );
+        }
+    }
+
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        assert!(a < columns && b < columns, "column index out of bounds");
+        for m in 0..rows {
+            self.as_slice_mut().swap(
+                m * row_stride + a * column_stride,
+                m * row_stride + b * column_stride,
+            );
+        }
+    }
+
+    /// the transpose, mutably borrowing the entries instead of moving them
+    #[inline]
+    pub fn transpose_view_mut(&mut self) -> GenericMatrix<SliceStorageMut<'_, S::Element>> {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        GenericMatrix {
+            data: SliceStorageMut::with_strides(
+                self.as_slice_mut(),
+                (columns, rows),
+                (column_stride, row_stride),
+            ),
+        }
+    }
+}
+
+impl<T: Scalar, S: OwnedStorage<Element = T>> GenericMatrix<S> {
+    /// a matrix of the given shape with every entry set to `value`
+    ///
+    /// panics for array backed matrices if `shape` is not their size.
+    #[inline]
+    pub fn from_element(shape: (usize, usize), value: T) -> Self {
+        Self {
+            data: S::from_element(shape, value),
+        }
+    }
+
+    #[inline]
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        let mut mat = self.clone();
+        mat.apply(f);
+        mat
+    }
+}
+
+impl<T: Scalar, S: StorageMut<Element = T>> GenericMatrix<S> {
+    #[inline]
+    pub fn apply<F: Fn(T) -> T>(&mut self, f: F) {
+        let (rows, columns) = self.size();
+        for n in 0..columns {
+            for m in 0..rows {
+                self[[m, n]] = f(self[[m, n]]);
+            }
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul, S: Storage<Element = T>> GenericMatrix<S> {
+    /// writes `self * rhs` into `out`, which needs as many rows as `self` and as many columns as `rhs`
+    pub fn mul_to<R: Storage<Element = T>, O: StorageMut<Element = T>>(
+        &self,
+        rhs: &GenericMatrix<R>,
+        out: &mut GenericMatrix<O>,
+    ) {
+        let (m, n) = self.size();
+        let (rhs_rows, p) = rhs.size();
+        assert_eq!(
+            n, rhs_rows,
+            "inner dimensions of a matrix product have to match"
+        );
+        assert_eq!(out.size(), (m, p));
+
+        let (lhs_rows, lhs_columns) = self.strides();
+        let (rhs_rows, rhs_columns) = rhs.strides();
+        let (out_rows, out_columns) = out.strides();
+        let (lhs, rhs, out) = (self.as_slice(), rhs.as_slice(), out.as_slice_mut());
+        for j in 0..p {
+            for i in 0..m {
+                out[i * out_rows + j * out_columns] = T::default();
+            }
+            for k in 0..n {
+                let factor = rhs[k * rhs_rows + j * rhs_columns];
+                for i in 0..m {
+                    out[i * out_rows + j * out_columns] +=
+                        lhs[i * lhs_rows + k * lhs_columns] * factor;
+                }
+            }
+        }
     }
+}
 
+impl<T: Scalar + Real + ClosedOps, S: Storage<Element = T>> GenericMatrix<S> {
+    /// square root of the sum of all squared entries
+    pub fn frobenius_norm(&self) -> T {
+        let mut sum = T::zero();
+        self.iter().for_each(|e| sum += *e * *e);
+        sum.sqrt()
+    }
+
+    /// the biggest sum of absolute values in a column
+    pub fn one_norm(&self) -> T {
+        let (rows, columns) = self.size();
+        let mut max = T::zero();
+        for n in 0..columns {
+            let mut sum = T::zero();
+            (0..rows).for_each(|m| sum += self[[m, n]].abs());
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    /// the biggest sum of absolute values in a row
+    pub fn infinity_norm(&self) -> T {
+        let (rows, columns) = self.size();
+        let mut max = T::zero();
+        for m in 0..rows {
+            let mut sum = T::zero();
+            (0..columns).for_each(|n| sum += self[[m, n]].abs());
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+}
+
+impl<T, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
     #[inline]
-    pub fn as_slice(&self) -> &[T] {
-        // this is safe because the underlying data structure of a matrix has length M * N
-        unsafe { std::slice::from_raw_parts(self as *const Self as *const T, M * N) }
+    pub const fn new(data: [[T; M]; N]) -> Self {
+        Self { data }
     }
 
     #[inline]
-    pub fn as_slice_mut(&mut self) -> &mut [T] {
-        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, M * N) }
+    pub fn layout() -> Layout {
+        Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<[T; M]>())
+            .unwrap()
     }
 
     #[inline]
-    pub const fn as_ptr(&self) -> *const T {
-        self as *const Self as *const T
+    pub fn as_array(&self) -> &[T; N] {
+        use std::convert::TryInto;
+        self.as_slice().try_into().unwrap()
     }
 
     #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        self as *mut Self as *mut T
+    pub fn as_array_mut(&mut self) -> &mut [T; N] {
+        use std::convert::TryInto;
+        self.as_slice_mut().try_into().unwrap()
     }
 
     #[inline]
@@ -135,23 +344,6 @@ impl<T: Scalar, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
             data: [[value; M]; N],
         }
     }
-
-    #[inline]
-    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
-        let mut vector = *self;
-        vector
-            .data
-            .iter_mut()
-            .for_each(|e| e.iter_mut().for_each(|e| *e = f(*e)));
-        vector
-    }
-
-    #[inline]
-    pub fn apply<F: Fn(T) -> T>(&mut self, f: F) {
-        self.data
-            .iter_mut()
-            .for_each(|e| e.iter_mut().for_each(|e| *e = f(*e)));
-    }
 }
 
 impl<T: Scalar + Signed, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
@@ -227,248 +419,215 @@ impl<T: Scalar + Zero + One + ClosedOps, const M: usize> SquareMatrix<T, { M }>
         mat
     }
 
-    #[inline]
-    pub fn determinant(&self) -> T {
+    /// closed forms up to 3x3, fraction-free Bareiss elimination above, which is exact for integers
+    ///
+    /// rows are only swapped on a pivot that is exactly zero, floats should use `determinant`.
+    pub fn determinant_exact(&self) -> T {
         match M {
             0 => T::one(),
             1 => self[[0, 0]],
-            2 => self[[0, 0]] * self[[1, 1]] - self[[1, 0]] * self[[1, 0]],
+            2 => self[[0, 0]] * self[[1, 1]] - self[[0, 1]] * self[[1, 0]],
             3 => {
-                let e11 = self[[0, 0]];
-                let e12 = self[[0, 1]];
-                let e13 = self[[0, 2]];
-
-                let e21 = self[[1, 0]];
-                let e22 = self[[1, 1]];
-                let e23 = self[[1, 2]];
-
-                let e31 = self[[2, 0]];
-                let e32 = self[[2, 1]];
-                let e33 = self[[2, 2]];
-
-                let minor_1 = e22 * e33 - e32 * e23;
-                let minor_2 = e21 * e33 - e31 * e23;
-                let minor_3 = e21 * e32 - e31 * e22;
-
-                e11 * minor_1 - e12 * minor_2 + e13 * minor_3
-            }
-            _ => {
-                unimplemented!("TODO: Add LU Decomposition")
+                let minor_1 = self[[1, 1]] * self[[2, 2]] - self[[2, 1]] * self[[1, 2]];
+                let minor_2 = self[[1, 0]] * self[[2, 2]] - self[[2, 0]] * self[[1, 2]];
+                let minor_3 = self[[1, 0]] * self[[2, 1]] - self[[2, 0]] * self[[1, 1]];
+                self[[0, 0]] * minor_1 - self[[0, 1]] * minor_2 + self[[0, 2]] * minor_3
             }
+            _ => self.bareiss(),
         }
     }
-}
-
-impl<T, const M: usize, const N: usize> Index<[usize; 2]> for Matrix<T, { M }, { N }> {
-    type Output = T;
-
-    fn index(&self, index: [usize; 2]) -> &Self::Output {
-        &self.data[index[1]][index[0]]
-    }
-}
 
-impl<T, const M: usize, const N: usize> IndexMut<[usize; 2]> for Matrix<T, { M }, { N }> {
-    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
-        &mut self.data[index[1]][index[0]]
-    }
-}
-
-impl<T: Scalar + ClosedAdd, const M: usize, const N: usize> Add for Matrix<T, { M }, { N }> {
-    type Output = Self;
-    #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut mat = Matrix::default();
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] = self[[m, n]] + rhs[[m, n]];
+    /// every division of the elimination is exact, so integer matrices never get rounded
+    fn bareiss(&self) -> T {
+        let mut a = *self;
+        let mut previous = T::one();
+        let mut negate = false;
+        for k in 0..M - 1 {
+            if a[[k, k]] == T::zero() {
+                match (k + 1..M).find(|i| a[[*i, k]] != T::zero()) {
+                    Some(i) => {
+                        a.swap_rows(k, i);
+                        negate = !negate;
+                    }
+                    None => return T::zero(),
+                }
             }
-        }
-        mat
-    }
-}
-
-impl<T: Scalar + ClosedAdd, const M: usize, const N: usize> AddAssign for Matrix<T, { M }, { N }> {
-    #[inline]
-    fn add_assign(&mut self, rhs: Self) {
-        for m in 0..M {
-            for n in 0..N {
-                self[[m, n]] += rhs[[m, n]];
+            for i in k + 1..M {
+                for j in k + 1..M {
+                    a[[i, j]] = (a[[i, j]] * a[[k, k]] - a[[i, k]] * a[[k, j]]) / previous;
+                }
             }
+            previous = a[[k, k]];
         }
-    }
-}
 
-impl<T: Scalar + ClosedSub, const M: usize, const N: usize> Sub for Matrix<T, { M }, { N }> {
-    type Output = Self;
-    #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut mat = Matrix::default();
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] = self[[m, n]] - rhs[[m, n]];
-            }
+        if negate {
+            T::zero() - a[[M - 1, M - 1]]
+        } else {
+            a[[M - 1, M - 1]]
         }
-        mat
     }
 }
 
-impl<T: Scalar + ClosedSub, const M: usize, const N: usize> SubAssign for Matrix<T, { M }, { N }> {
+impl<S: Storage> Index<[usize; 2]> for GenericMatrix<S> {
+    type Output = S::Element;
+
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
-        for m in 0..M {
-            for n in 0..N {
-                self[[m, n]] -= rhs[[m, n]];
-            }
-        }
+    fn index(&self, index: [usize; 2]) -> &Self::Output {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        assert!(
+            index[0] < rows && index[1] < columns,
+            "matrix index out of bounds"
+        );
+        &self.as_slice()[index[0] * row_stride + index[1] * column_stride]
     }
 }
 
-impl<T: Scalar + ClosedMul + ClosedAdd, const M: usize, const N: usize, const P: usize>
-    Mul<Matrix<T, { N }, { P }>> for Matrix<T, { M }, { N }>
-{
-    type Output = Matrix<T, { M }, { P }>;
+impl<S: StorageMut> IndexMut<[usize; 2]> for GenericMatrix<S> {
     #[inline]
-    fn mul(self, rhs: Matrix<T, { N }, { P }>) -> Self::Output {
-        let mut mat = Matrix::default();
-        for m in 0..M {
-            for p in 0..P {
-                for n in 0..N {
-                    mat[[m, p]] += self[[m, n]] * rhs[[n, p]];
-                }
-            }
-        }
-        mat
+    fn index_mut(&mut self, index: [usize; 2]) -> &mut Self::Output {
+        let (rows, columns) = self.size();
+        let (row_stride, column_stride) = self.strides();
+        assert!(
+            index[0] < rows && index[1] < columns,
+            "matrix index out of bounds"
+        );
+        &mut self.as_slice_mut()[index[0] * row_stride + index[1] * column_stride]
     }
 }
 
-impl<T: Scalar + ClosedMul, const M: usize, const N: usize> Mul<T> for Matrix<T, { M }, { N }> {
-    type Output = Self;
+macro_rules! impl_elementwise_op {
+    ($($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $bound:ident, $token:tt);*) => {
+        $(
+            impl<T: Scalar + $bound, S: OwnedStorage<Element = T>> $op for GenericMatrix<S> {
+                type Output = Self;
 
-    fn mul(self, rhs: T) -> Self::Output {
-        let mut mat = self;
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] *= rhs
+                #[inline]
+                fn $method(mut self, rhs: Self) -> Self::Output {
+                    self.$assign_method(&rhs);
+                    self
+                }
             }
-        }
-        self
-    }
-}
 
-impl<T: Scalar + ClosedMul, const M: usize, const N: usize> MulAssign<T>
-    for Matrix<T, { M }, { N }>
-{
-    fn mul_assign(&mut self, rhs: T) {
-        for m in 0..M {
-            for n in 0..N {
-                self[[m, n]] *= rhs
+            impl<T: Scalar + $bound, S: OwnedStorage<Element = T>> $assign_op for GenericMatrix<S> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    self.$assign_method(&rhs);
+                }
             }
-        }
-    }
-}
 
-impl<T: Scalar + ClosedDiv, const M: usize, const N: usize> Div<T> for Matrix<T, { M }, { N }> {
-    type Output = Self;
+            impl<'r, T: Scalar + $bound, S: OwnedStorage<Element = T>, R: Storage<Element = T>>
+                $op<&'r GenericMatrix<R>> for GenericMatrix<S>
+            {
+                type Output = Self;
 
-    fn div(self, rhs: T) -> Self::Output {
-        let mut mat = self;
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] /= rhs
+                #[inline]
+                fn $method(mut self, rhs: &'r GenericMatrix<R>) -> Self::Output {
+                    self.$assign_method(rhs);
+                    self
+                }
             }
-        }
-        self
-    }
-}
 
-impl<T: Scalar + ClosedDiv, const M: usize, const N: usize> DivAssign<T>
-    for Matrix<T, { M }, { N }>
-{
-    fn div_assign(&mut self, rhs: T) {
-        for m in 0..M {
-            for n in 0..N {
-                self[[m, n]] /= rhs
+            impl<'r, T: Scalar + $bound, S: StorageMut<Element = T>, R: Storage<Element = T>>
+                $assign_op<&'r GenericMatrix<R>> for GenericMatrix<S>
+            {
+                #[inline]
+                fn $assign_method(&mut self, rhs: &'r GenericMatrix<R>) {
+                    assert_eq!(self.size(), rhs.size());
+                    // a borrowed buffer may hold more than the entries, which must not be touched
+                    let (rows, columns) = self.size();
+                    let len = rows * columns;
+                    if self.is_contiguous()
+                        && rhs.is_contiguous()
+                        && self.as_slice().len() == len
+                        && rhs.as_slice().len() == len
+                    {
+                        self.as_slice_mut()
+                            .iter_mut()
+                            .zip(rhs.as_slice())
+                            .for_each(|(a, b)| *a $token *b);
+                        return;
+                    }
+                    for n in 0..columns {
+                        for m in 0..rows {
+                            self[[m, n]] $token rhs[[m, n]];
+                        }
+                    }
+                }
             }
-        }
-    }
+        )*
+    };
 }
 
-impl<T: Scalar + ClosedNeg, const M: usize, const N: usize> Neg for Matrix<T, { M }, { N }> {
-    type Output = Self;
+impl_elementwise_op!(
+    Add, add, AddAssign, add_assign, ClosedAdd, +=;
+    Sub, sub, SubAssign, sub_assign, ClosedSub, -=
+);
+
+macro_rules! impl_scalar_op {
+    ($($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $bound:ident, $token:tt);*) => {
+        $(
+            impl<T: Scalar + $bound, S: OwnedStorage<Element = T>> $op<T> for GenericMatrix<S> {
+                type Output = Self;
+
+                #[inline]
+                fn $method(mut self, rhs: T) -> Self::Output {
+                    self.$assign_method(rhs);
+                    self
+                }
+            }
 
-    fn neg(self) -> Self::Output {
-        let mut mat = self;
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] = -mat[[m, n]]
+            impl<T: Scalar + $bound, S: OwnedStorage<Element = T>> $assign_op<T> for GenericMatrix<S> {
+                #[inline]
+                fn $assign_method(&mut self, rhs: T) {
+                    self.as_slice_mut().iter_mut().for_each(|e| *e $token rhs);
+                }
             }
-        }
-        mat
-    }
+        )*
+    };
 }
 
-impl<T: Scalar + ClosedRem, const M: usize, const N: usize> Rem<T> for Matrix<T, { M }, { N }> {
+impl_scalar_op!(
+    Mul, mul, MulAssign, mul_assign, ClosedMul, *=;
+    Div, div, DivAssign, div_assign, ClosedDiv, /=;
+    Rem, rem, RemAssign, rem_assign, ClosedRem, %=
+);
+
+impl<T: Scalar + ClosedNeg, S: OwnedStorage<Element = T>> Neg for GenericMatrix<S> {
     type Output = Self;
 
-    fn rem(self, rhs: T) -> Self::Output {
-        let mut mat = self;
-        for m in 0..M {
-            for n in 0..N {
-                mat[[m, n]] %= rhs;
-            }
-        }
-        mat
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        self.apply(|e| -e);
+        self
     }
 }
 
-impl<T: Scalar + ClosedRem, const M: usize, const N: usize> RemAssign<T>
-    for Matrix<T, { M }, { N }>
+impl<T: Scalar + ClosedMul + ClosedAdd, const M: usize, const N: usize, const P: usize>
+    Mul<Matrix<T, { N }, { P }>> for Matrix<T, { M }, { N }>
 {
-    fn rem_assign(&mut self, rhs: T) {
-        for m in 0..M {
-            for n in 0..N {
-                self[[m, n]] %= rhs;
-            }
-        }
+    type Output = Matrix<T, { M }, { P }>;
+    #[inline]
+    fn mul(self, rhs: Matrix<T, { N }, { P }>) -> Self::Output {
+        let mut mat = Matrix::default();
+        self.mul_to(&rhs, &mut mat);
+        mat
     }
 }
 
-// // this sadly doesnt work really
-// // TODO: number conversion
-// impl<T: Default + Copy, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
-//     #[inline]
-//     pub fn to_other_type<U: Default + Copy + CastInts>(&self) -> Matrix<U, { M }, { N }> {
-//         let mut mat = Matrix::default();
-//
-//         for m in 0..M {
-//             for n in 0..N {
-//                 mat[[m, n]] = U::try_from(self[[m, n]]).unwrap_or_default()
-//             }
-//         }
-//
-//         mat
-//     }
-// }
-
+/// parses like `FromStr`, panics if `rhs` is malformed or has the wrong size
 impl<T: FromStr + Default + Copy, const M: usize, const N: usize> From<String>
     for Matrix<T, { M }, { N }>
 {
     fn from(rhs: String) -> Self {
-        let mut mat = Matrix::default();
-
-        rhs.split(";").into_iter().enumerate().for_each(|(n, col)| {
-            col.split(" ").into_iter().enumerate().for_each(|(m, val)| {
-                mat[[m, n]] = val.to_string().parse().unwrap_or_else(|t| T::default())
-            })
-        });
-
-        mat
+        rhs.parse()
+            .unwrap_or_else(|e| panic!("invalid matrix: {}", e))
     }
 }
 
 #[cfg(test)]
 mod mat_tests {
-    use crate::algebra::linear::{Matrix, Vector};
+    use crate::algebra::linear::{DMatrix, Matrix, Vector};
 
     #[test]
     fn parse() {
@@ -479,4 +638,43 @@ mod mat_tests {
         assert_eq!(vec, Vector::new([[2, 3, -5]]));
         assert_eq!(mat, Matrix::new([[2, 3], [-1, 4], [0, -2]]));
     }
+
+    #[test]
+    fn integer_determinant() {
+        use crate::algebra::linear::SquareMatrix;
+
+        assert_eq!(
+            SquareMatrix::<i32, 2>::new([[1, 3], [2, 4]]).determinant_exact(),
+            -2
+        );
+        assert_eq!(
+            SquareMatrix::<i32, 3>::new([[2, 1, 1], [0, 3, 1], [1, 2, 2]]).determinant_exact(),
+            6
+        );
+        let mat = SquareMatrix::<i64, 4>::new([
+            [2, 1, 7, 3],
+            [4, 3, -1, 2],
+            [-2, 5, 0, 6],
+            [1, -4, 2, 8],
+        ]);
+        assert_eq!(mat.determinant_exact(), 2323);
+
+        // the first pivot is zero, so the rows have to be swapped
+        let mut swapped = SquareMatrix::<i32, 5>::mul_identity();
+        swapped.swap_columns(0, 4);
+        assert_eq!(swapped.determinant_exact(), -1);
+        swapped[[2, 2]] = 0;
+        assert_eq!(swapped.determinant_exact(), 0);
+    }
+
+    #[test]
+    fn scale() {
+        let mat = Matrix::<i32, 2, 2>::new([[2, -4], [6, 8]]);
+        assert_eq!(mat * 3, Matrix::new([[6, -12], [18, 24]]));
+        assert_eq!(mat / 2, Matrix::new([[1, -2], [3, 4]]));
+
+        let dynamic = DMatrix::from(mat);
+        assert_eq!((dynamic.clone() * 3).as_slice(), [6, -12, 18, 24]);
+        assert_eq!((dynamic / 2).as_slice(), [1, -2, 3, 4]);
+    }
 }
@@ -1,4 +1,24 @@
+pub mod block;
+pub mod cholesky;
+pub mod decomposition;
+pub mod eigen;
+pub mod error;
+pub mod inverse;
+pub mod lu;
 pub mod mat;
+pub mod qr;
 pub mod set;
+pub mod svd;
+pub mod transform;
+pub mod view;
+pub use cholesky::{Cholesky, LDLT};
+pub use decomposition::{DCholesky, DSymmetricEigen, Decomposition, DLU, DQR, DSVD};
+pub use eigen::SymmetricEigen;
+pub use error::DecompositionError;
+pub use lu::LU;
+pub use mat::GenericMatrix;
 pub use mat::Matrix;
 pub use mat::SquareMatrix;
+pub use qr::QR;
+pub use svd::SVD;
+pub use view::{MatrixView, MatrixViewMut};
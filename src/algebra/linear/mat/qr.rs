@@ -0,0 +1,244 @@
+use crate::algebra::linear::{GenericMatrix, Matrix, Scalar, SquareMatrix, StorageMut, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// QR decomposition by Householder reflections: `A = Q * R`
+///
+/// `Q` is orthogonal and `R` is upper triangular.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct QR<T, const M: usize, const N: usize> {
+    pub q: SquareMatrix<T, { M }>,
+    pub r: Matrix<T, { M }, { N }>,
+}
+
+/// reflects `r` to upper triangular form and accumulates the reflections in `q`, returns `det(Q)`
+///
+/// `q` has to start out as the identity and `v` needs as many entries as `r` has rows.
+/// With `columns` the remaining column with the biggest norm is moved to the front before every
+/// step, which makes the diagonal of `R` decrease in magnitude. `columns` has to start out as the
+/// identity permutation and receives the column swaps.
+pub(crate) fn householder<T, S, Q>(
+    r: &mut GenericMatrix<S>,
+    q: &mut GenericMatrix<Q>,
+    v: &mut [T],
+    mut columns: Option<&mut [usize]>,
+) -> T
+where
+    T: Scalar + Real + ClosedOps + Float,
+    S: StorageMut<Element = T>,
+    Q: StorageMut<Element = T>,
+{
+    let two = T::one() + T::one();
+    let (m, n) = r.size();
+    assert_eq!(q.size(), (m, m));
+    assert_eq!(v.len(), m);
+    let mut det = T::one();
+
+    let steps = if m == 0 { 0 } else { n.min(m - 1) };
+    for k in 0..steps {
+        if let Some(columns) = columns.as_deref_mut() {
+            let mut best = (k, -T::one());
+            for j in k..n {
+                let mut norm = T::zero();
+                for i in k..m {
+                    norm += r[[i, j]] * r[[i, j]];
+                }
+                if norm > best.1 {
+                    best = (j, norm);
+                }
+            }
+            if best.0 != k {
+                r.swap_columns(k, best.0);
+                columns.swap(k, best.0);
+            }
+        }
+
+        let mut norm = T::zero();
+        for i in k..m {
+            norm += r[[i, k]] * r[[i, k]];
+        }
+        let norm = norm.sqrt();
+        if norm.is_zero() {
+            continue;
+        }
+
+        // reflect onto -sign(x0) * |x| * e0 to avoid cancellation
+        let alpha = if r[[k, k]] < T::zero() { norm } else { -norm };
+        for i in k..m {
+            v[i] = r[[i, k]];
+        }
+        v[k] -= alpha;
+
+        let mut v_norm = T::zero();
+        v[k..m].iter().for_each(|e| v_norm += *e * *e);
+        if v_norm.is_zero() {
+            continue;
+        }
+        det = -det;
+
+        // R = H * R
+        for j in k..n {
+            let mut dot = T::zero();
+            for i in k..m {
+                dot += v[i] * r[[i, j]];
+            }
+            let factor = two * dot / v_norm;
+            for i in k..m {
+                r[[i, j]] -= factor * v[i];
+            }
+        }
+
+        // Q = Q * H
+        for i in 0..m {
+            let mut dot = T::zero();
+            for l in k..m {
+                dot += q[[i, l]] * v[l];
+            }
+            let factor = two * dot / v_norm;
+            for l in k..m {
+                q[[i, l]] -= factor * v[l];
+            }
+        }
+
+        for i in k + 1..m {
+            r[[i, k]] = T::zero();
+        }
+    }
+
+    det
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const M: usize, const N: usize> QR<T, { M }, { N }> {
+    pub fn new(matrix: Matrix<T, { M }, { N }>) -> Self {
+        let mut q = SquareMatrix::<T, { M }>::mul_identity();
+        let mut r = matrix;
+        let mut v = Vector::<T, { M }>::default();
+        householder(&mut r, &mut q, v.as_slice_mut(), None);
+
+        Self { q, r }
+    }
+
+    /// returns the first `N` columns of `Q`, only meaningful for `M >= N`
+    pub fn thin_q(&self) -> Matrix<T, { M }, { N }> {
+        let mut thin = Matrix::<T, { M }, { N }>::default();
+        for i in 0..M {
+            for j in 0..N.min(M) {
+                thin[[i, j]] = self.q[[i, j]];
+            }
+        }
+        thin
+    }
+
+    /// returns the upper `N` rows of `R`, only meaningful for `M >= N`
+    pub fn thin_r(&self) -> SquareMatrix<T, { N }> {
+        let mut thin = SquareMatrix::<T, { N }>::default();
+        for i in 0..N.min(M) {
+            for j in 0..N {
+                thin[[i, j]] = self.r[[i, j]];
+            }
+        }
+        thin
+    }
+
+    /// returns `x` minimizing `|A * x - b|`
+    ///
+    /// returns `None` if `A` has fewer rows than columns or does not have full column rank
+    pub fn least_squares(&self, b: Vector<T, { M }>) -> Option<Vector<T, { N }>> {
+        if M < N {
+            return None;
+        }
+
+        let mut scale = T::zero();
+        self.r.as_slice().iter().for_each(|e| {
+            if e.abs() > scale {
+                scale = e.abs()
+            }
+        });
+        // rounding errors of the reflections grow with the number of rows
+        let mut tolerance = T::zero();
+        for _ in 0..M {
+            tolerance += scale * <T as Float>::EPSILON;
+        }
+
+        // Q^T * b, only the first N entries are needed
+        let mut x = Vector::<T, { N }>::default();
+        for j in 0..N {
+            let mut dot = T::zero();
+            for i in 0..M {
+                dot += self.q[[i, j]] * b[i];
+            }
+            x[j] = dot;
+        }
+
+        for i in (0..N).rev() {
+            if self.r[[i, i]].abs() <= tolerance {
+                return None;
+            }
+            let mut sum = x[i];
+            for j in i + 1..N {
+                sum -= self.r[[i, j]] * x[j];
+            }
+            x[i] = sum / self.r[[i, i]];
+        }
+
+        Some(x)
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    #[inline]
+    pub fn qr(&self) -> QR<T, { M }, { N }> {
+        QR::new(*self)
+    }
+
+    #[inline]
+    pub fn least_squares(&self, b: Vector<T, { M }>) -> Option<Vector<T, { N }>> {
+        self.qr().least_squares(b)
+    }
+}
+
+#[cfg(test)]
+mod qr_tests {
+    use crate::algebra::linear::{Matrix, SquareMatrix, Vector};
+
+    #[test]
+    fn reconstruct() {
+        let mat = Matrix::<f64, 4, 3>::new([
+            [1.0, 2.0, -1.0, 3.0],
+            [4.0, 0.0, 2.0, 1.0],
+            [-2.0, 5.0, 1.0, 0.0],
+        ]);
+        let qr = mat.qr();
+
+        let diff = qr.q * qr.r - mat;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+
+        let thin = qr.thin_q() * qr.thin_r() - mat;
+        assert!(thin.as_slice().iter().all(|e| e.abs() < 1e-12));
+
+        let orthogonal = qr.q.transpose() * qr.q - SquareMatrix::mul_identity();
+        assert!(orthogonal.as_slice().iter().all(|e| e.abs() < 1e-12));
+
+        for j in 0..3 {
+            for i in j + 1..4 {
+                assert_eq!(qr.r[[i, j]], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn least_squares() {
+        // fit y = 1 + 2x through points lying exactly on the line
+        let mat = Matrix::<f64, 4, 2>::new([[1.0, 1.0, 1.0, 1.0], [0.0, 1.0, 2.0, 3.0]]);
+        let b = Vector::from([1.0, 3.0, 5.0, 7.0]);
+        let x = mat.least_squares(b).unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-12);
+        assert!((x[1] - 2.0).abs() < 1e-12);
+
+        let deficient = Matrix::<f64, 3, 2>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+        assert!(deficient
+            .least_squares(Vector::from([1.0, 0.0, 0.0]))
+            .is_none());
+    }
+}
@@ -8,7 +8,9 @@ use fructose::algebra::module::Module;
 use fructose::algebra::ring::CommutativeRing;
 use fructose::operators::{Additive, ClosedAdd, ClosedMul, ClosedOps, Multiplicative};
 use fructose::properties::general::{Associative, Commutative, Identity, Invertible, Set, Total};
+use fructose::properties::helpers::float::Float;
 use fructose::properties::helpers::identity::{One, Zero};
+use fructose::specific::complex::Real;
 use std::iter::Sum;
 
 impl<T: Scalar + ClosedAdd, const M: usize, const N: usize> Set<Additive>
@@ -81,28 +83,29 @@ impl<T: Scalar + ClosedAdd + ClosedMul + Commutative<Multiplicative>, const N: u
 {
 }
 
-impl<T: Scalar + ClosedAdd + ClosedMul + Identity<Multiplicative> + PartialEq, const N: usize>
-    Identity<Multiplicative> for SquareMatrix<T, { N }>
+impl<
+        T: Scalar + ClosedOps + Identity<Additive> + Identity<Multiplicative> + PartialEq,
+        const N: usize,
+    > Identity<Multiplicative> for SquareMatrix<T, { N }>
 {
     fn identity() -> Self {
-        Self::broadcast(T::identity())
+        Self::mul_identity()
     }
 
     fn is_identity(&self) -> bool {
-        *self == Self::identity()
+        *self == Self::mul_identity()
     }
 }
 
-// TODO: add matrix inverse
-impl<T: Scalar + ClosedAdd + ClosedMul + Invertible<Multiplicative>, const N: usize>
+impl<T: Scalar + Real + ClosedOps + Float + Invertible<Multiplicative>, const N: usize>
     Invertible<Multiplicative> for SquareMatrix<T, { N }>
 {
     fn inverse(&self) -> Self {
-        unimplemented!()
+        self.try_inverse().expect("matrix is singular")
     }
 
     fn inverted(&mut self) {
-        *self = self.inverse()
+        *self = <Self as Invertible<Multiplicative>>::inverse(self)
     }
 }
 
@@ -0,0 +1,341 @@
+use crate::algebra::linear::{GenericMatrix, Matrix, Scalar, SquareMatrix, StorageMut, Vector};
+use fructose::operators::ClosedOps;
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+
+/// maximum number of one-sided jacobi sweeps
+const MAX_SWEEPS: usize = 64;
+
+/// singular value decomposition: `A = U * Σ * V^T`
+///
+/// `U` and `V^T` are orthogonal, `sigma` is the rectangular diagonal matrix `Σ`
+/// with the singular values sorted in descending order on its diagonal.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SVD<T, const M: usize, const N: usize> {
+    pub u: SquareMatrix<T, { M }>,
+    pub sigma: Matrix<T, { M }, { N }>,
+    pub v_t: SquareMatrix<T, { N }>,
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const M: usize, const N: usize> SVD<T, { M }, { N }> {
+    pub fn new(matrix: Matrix<T, { M }, { N }>) -> Self {
+        let mut sigma = Matrix::<T, { M }, { N }>::default();
+        if M >= N {
+            let (u, values, v) = one_sided_jacobi(matrix);
+            for i in 0..N {
+                sigma[[i, i]] = values[i];
+            }
+            Self {
+                u,
+                sigma,
+                v_t: v.transpose(),
+            }
+        } else {
+            // A^T = U' * Σ' * V'^T  =>  A = V' * Σ'^T * U'^T
+            let (u, values, v) = one_sided_jacobi(matrix.transpose());
+            for i in 0..M {
+                sigma[[i, i]] = values[i];
+            }
+            Self {
+                u: v,
+                sigma,
+                v_t: u.transpose(),
+            }
+        }
+    }
+
+    /// the number of singular values, `min(M, N)`
+    #[inline]
+    pub const fn len(&self) -> usize {
+        if M < N {
+            M
+        } else {
+            N
+        }
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        M == 0 || N == 0
+    }
+
+    /// returns the `i`th biggest singular value
+    #[inline]
+    pub fn singular_value(&self, i: usize) -> T {
+        self.sigma[[i, i]]
+    }
+
+    /// the number of singular values bigger than `eps`
+    pub fn rank(&self, eps: T) -> usize {
+        (0..self.len())
+            .filter(|i| self.singular_value(*i) > eps)
+            .count()
+    }
+
+    /// ratio of the biggest to the smallest singular value, infinite for singular matrices
+    pub fn condition_number(&self) -> T {
+        if self.is_empty() {
+            return T::one();
+        }
+        let min = self.singular_value(self.len() - 1);
+        if min.is_zero() {
+            <T as Float>::INFINITY
+        } else {
+            self.singular_value(0) / min
+        }
+    }
+
+    /// Moore-Penrose pseudo-inverse `V * Σ^+ * U^T`, singular values not bigger than `eps` are treated as zero
+    pub fn pseudo_inverse(&self, eps: T) -> Matrix<T, { N }, { M }> {
+        let mut inv = Matrix::<T, { N }, { M }>::default();
+        for k in 0..self.len() {
+            let value = self.singular_value(k);
+            if value <= eps {
+                continue;
+            }
+            for i in 0..N {
+                let v = self.v_t[[k, i]] / value;
+                for j in 0..M {
+                    inv[[i, j]] += v * self.u[[j, k]];
+                }
+            }
+        }
+        inv
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + Float, const M: usize, const N: usize> Matrix<T, { M }, { N }> {
+    #[inline]
+    pub fn svd(&self) -> SVD<T, { M }, { N }> {
+        SVD::new(*self)
+    }
+
+    #[inline]
+    pub fn pseudo_inverse(&self, eps: T) -> Matrix<T, { N }, { M }> {
+        self.svd().pseudo_inverse(eps)
+    }
+
+    #[inline]
+    pub fn rank(&self, eps: T) -> usize {
+        self.svd().rank(eps)
+    }
+
+    #[inline]
+    pub fn condition_number(&self) -> T {
+        self.svd().condition_number()
+    }
+}
+
+/// one-sided jacobi svd for `R >= C`, returns the full `U`, the singular values in descending order and `V`
+fn one_sided_jacobi<T: Scalar + Real + ClosedOps + Float, const R: usize, const C: usize>(
+    matrix: Matrix<T, { R }, { C }>,
+) -> (
+    SquareMatrix<T, { R }>,
+    Vector<T, { C }>,
+    SquareMatrix<T, { C }>,
+) {
+    let mut w = matrix;
+    let mut u = SquareMatrix::<T, { R }>::default();
+    let mut values = Vector::<T, { C }>::default();
+    let mut v = SquareMatrix::<T, { C }>::mul_identity();
+    jacobi(&mut w, &mut u, values.as_slice_mut(), &mut v);
+    (u, values, v)
+}
+
+/// one-sided jacobi svd of the `R`x`C` matrix `w` for `R >= C`
+///
+/// `u` has to start out as zeros and `v` as the identity, they receive the full `U` and `V` and
+/// `values` the singular values in descending order. `w` is left as `U * Σ` with its columns sorted.
+pub(crate) fn jacobi<T, W, U, V>(
+    w: &mut GenericMatrix<W>,
+    u: &mut GenericMatrix<U>,
+    values: &mut [T],
+    v: &mut GenericMatrix<V>,
+) where
+    T: Scalar + Real + ClosedOps + Float,
+    W: StorageMut<Element = T>,
+    U: StorageMut<Element = T>,
+    V: StorageMut<Element = T>,
+{
+    let (rows, columns) = w.size();
+    assert!(rows >= columns);
+    assert_eq!(values.len(), columns);
+    assert_eq!(u.size(), (rows, rows));
+    assert_eq!(v.size(), (columns, columns));
+
+    let two = T::one() + T::one();
+    let eps = <T as Float>::EPSILON;
+
+    // orthogonalize the columns of W pairwise until all of them are orthogonal
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..columns {
+            for q in p + 1..columns {
+                let (mut alpha, mut beta, mut gamma) = (T::zero(), T::zero(), T::zero());
+                for i in 0..rows {
+                    alpha += w[[i, p]] * w[[i, p]];
+                    beta += w[[i, q]] * w[[i, q]];
+                    gamma += w[[i, p]] * w[[i, q]];
+                }
+                if gamma.abs() <= eps * (alpha * beta).sqrt() || gamma.is_zero() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = T::one() / (zeta.abs() + (zeta * zeta + T::one()).sqrt());
+                let t = if zeta < T::zero() { -t } else { t };
+                let c = T::one() / (t * t + T::one()).sqrt();
+                let s = c * t;
+
+                for i in 0..rows {
+                    let (wp, wq) = (w[[i, p]], w[[i, q]]);
+                    w[[i, p]] = c * wp - s * wq;
+                    w[[i, q]] = s * wp + c * wq;
+                }
+                for i in 0..columns {
+                    let (vp, vq) = (v[[i, p]], v[[i, q]]);
+                    v[[i, p]] = c * vp - s * vq;
+                    v[[i, q]] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    for j in 0..columns {
+        let mut norm = T::zero();
+        for i in 0..rows {
+            norm += w[[i, j]] * w[[i, j]];
+        }
+        values[j] = norm.sqrt();
+    }
+
+    // selection sort in descending order, swapping the columns along
+    for i in 0..columns {
+        let mut max = i;
+        for j in i + 1..columns {
+            if values[j] > values[max] {
+                max = j;
+            }
+        }
+        if max != i {
+            values.swap(i, max);
+            w.swap_columns(i, max);
+            v.swap_columns(i, max);
+        }
+    }
+
+    let mut tolerance = T::zero();
+    if columns > 0 {
+        for _ in 0..rows {
+            tolerance += values[0] * eps;
+        }
+    }
+
+    let mut filled = vec![false; rows];
+    for j in 0..columns {
+        if values[j] > tolerance {
+            for i in 0..rows {
+                u[[i, j]] = w[[i, j]] / values[j];
+            }
+            filled[j] = true;
+        }
+    }
+
+    // complete U to an orthonormal basis with the unit vectors that are the least covered by it
+    let mut column = vec![T::zero(); rows];
+    for j in 0..rows {
+        if filled[j] {
+            continue;
+        }
+        let mut best = (0, -T::one());
+        for k in 0..rows {
+            let mut residual = T::one();
+            for (f, _) in filled.iter().enumerate().filter(|(_, f)| **f) {
+                residual -= u[[k, f]] * u[[k, f]];
+            }
+            if residual > best.1 {
+                best = (k, residual);
+            }
+        }
+
+        column.iter_mut().for_each(|e| *e = T::zero());
+        column[best.0] = T::one();
+        for (f, _) in filled.iter().enumerate().filter(|(_, f)| **f) {
+            let dot = u[[best.0, f]];
+            for i in 0..rows {
+                column[i] -= dot * u[[i, f]];
+            }
+        }
+        let mut norm = T::zero();
+        column.iter().for_each(|e| norm += *e * *e);
+        let norm = norm.sqrt();
+        for i in 0..rows {
+            u[[i, j]] = column[i] / norm;
+        }
+        filled[j] = true;
+    }
+}
+
+#[cfg(test)]
+mod svd_tests {
+    use crate::algebra::linear::{Matrix, SquareMatrix};
+
+    fn assert_orthogonal<const N: usize>(mat: SquareMatrix<f64, N>) {
+        let diff = mat.transpose() * mat - SquareMatrix::mul_identity();
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn reconstruct() {
+        let tall = Matrix::<f64, 4, 3>::new([
+            [1.0, 2.0, -1.0, 3.0],
+            [4.0, 0.0, 2.0, 1.0],
+            [-2.0, 5.0, 1.0, 0.0],
+        ]);
+        let svd = tall.svd();
+        assert_orthogonal(svd.u);
+        assert_orthogonal(svd.v_t);
+        let diff = svd.u * svd.sigma * svd.v_t - tall;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+        assert!(svd.singular_value(0) >= svd.singular_value(1));
+        assert!(svd.singular_value(1) >= svd.singular_value(2));
+
+        let wide = Matrix::<f64, 2, 3>::new([[3.0, 2.0], [2.0, 3.0], [2.0, -2.0]]);
+        let svd = wide.svd();
+        assert_orthogonal(svd.u);
+        assert_orthogonal(svd.v_t);
+        let diff = svd.u * svd.sigma * svd.v_t - wide;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+        assert!((svd.singular_value(0) - 5.0).abs() < 1e-12);
+        assert!((svd.singular_value(1) - 3.0).abs() < 1e-12);
+        assert!((svd.condition_number() - 5.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mat = Matrix::<f64, 3, 3>::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+        let svd = mat.svd();
+        assert_eq!(svd.rank(1e-10), 2);
+        assert_orthogonal(svd.u);
+        let diff = svd.u * svd.sigma * svd.v_t - mat;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+        assert!(mat.condition_number() > 1e12);
+
+        // A * A^+ * A = A
+        let pinv = mat.pseudo_inverse(1e-10);
+        let diff = mat * pinv * mat - mat;
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-10));
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let mat = Matrix::<f64, 3, 2>::new([[1.0, 1.0, 1.0], [0.0, 1.0, 2.0]]);
+        let pinv = mat.pseudo_inverse(1e-10);
+        let diff = pinv * mat - SquareMatrix::<f64, 2>::mul_identity();
+        assert!(diff.as_slice().iter().all(|e| e.abs() < 1e-12));
+    }
+}
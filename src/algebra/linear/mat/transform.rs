@@ -0,0 +1,279 @@
+//! homogeneous transforms for column vectors, `transformed = matrix * vector`
+//!
+//! `rh`/`lh` is the handedness of the view space, right-handed views look along `-z`,
+//! left-handed ones along `+z`. `zo` projections map depth to `[0, 1]` (vulkan, directx, metal),
+//! `no` projections to `[-1, 1]` (opengl).
+use crate::algebra::linear::{Scalar, SquareMatrix, Vector};
+use fructose::operators::ClosedOps;
+use fructose::specific::complex::Real;
+
+impl<T: Scalar + Real + ClosedOps> SquareMatrix<T, 4> {
+    #[inline]
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        let mut mat = Self::mul_identity();
+        for i in 0..3 {
+            mat[[i, 3]] = translation[i];
+        }
+        mat
+    }
+
+    /// scales every axis by its own factor
+    #[inline]
+    pub fn from_scale(scale: Vector<T, 3>) -> Self {
+        let mut mat = Self::mul_identity();
+        for i in 0..3 {
+            mat[[i, i]] = scale[i];
+        }
+        mat
+    }
+
+    /// counter-clockwise rotation by `angle` radians around `axis`, which does not need to be normalized
+    pub fn from_axis_angle(axis: Vector<T, 3>, angle: T) -> Self {
+        let axis = axis.normalized();
+        let (x, y, z) = (axis[0], axis[1], axis[2]);
+        let (sin, cos) = angle.sin_cos();
+        let t = T::one() - cos;
+
+        let mut mat = Self::mul_identity();
+        mat[[0, 0]] = t * x * x + cos;
+        mat[[0, 1]] = t * x * y - sin * z;
+        mat[[0, 2]] = t * x * z + sin * y;
+        mat[[1, 0]] = t * x * y + sin * z;
+        mat[[1, 1]] = t * y * y + cos;
+        mat[[1, 2]] = t * y * z - sin * x;
+        mat[[2, 0]] = t * x * z - sin * y;
+        mat[[2, 1]] = t * y * z + sin * x;
+        mat[[2, 2]] = t * z * z + cos;
+        mat
+    }
+
+    /// view matrix of a camera at `eye` looking at `target`, the camera looks along `-z`
+    #[inline]
+    pub fn look_at_rh(eye: Vector<T, 3>, target: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let forward = (target - eye).normalized();
+        let side = forward.cross(up).normalized();
+        let up = side.cross(forward);
+        Self::look_basis(side, up, -forward, eye)
+    }
+
+    /// view matrix of a camera at `eye` looking at `target`, the camera looks along `+z`
+    #[inline]
+    pub fn look_at_lh(eye: Vector<T, 3>, target: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let forward = (target - eye).normalized();
+        let side = up.cross(forward).normalized();
+        let up = forward.cross(side);
+        Self::look_basis(side, up, forward, eye)
+    }
+
+    /// `fov_y` is the vertical field of view in radians, `aspect` is width / height
+    #[inline]
+    pub fn perspective_rh_zo(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, near, far, true, true)
+    }
+
+    #[inline]
+    pub fn perspective_rh_no(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, near, far, true, false)
+    }
+
+    #[inline]
+    pub fn perspective_lh_zo(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, near, far, false, true)
+    }
+
+    #[inline]
+    pub fn perspective_lh_no(fov_y: T, aspect: T, near: T, far: T) -> Self {
+        Self::perspective(fov_y, aspect, near, far, false, false)
+    }
+
+    #[inline]
+    pub fn orthographic_rh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic([left, right, bottom, top], near, far, true, true)
+    }
+
+    #[inline]
+    pub fn orthographic_rh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic([left, right, bottom, top], near, far, true, false)
+    }
+
+    #[inline]
+    pub fn orthographic_lh_zo(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic([left, right, bottom, top], near, far, false, true)
+    }
+
+    #[inline]
+    pub fn orthographic_lh_no(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Self::orthographic([left, right, bottom, top], near, far, false, false)
+    }
+
+    /// rows are the camera axes, the translation moves `eye` into the origin
+    fn look_basis(
+        side: Vector<T, 3>,
+        up: Vector<T, 3>,
+        back: Vector<T, 3>,
+        eye: Vector<T, 3>,
+    ) -> Self {
+        let mut mat = Self::mul_identity();
+        for (i, axis) in [side, up, back].iter().enumerate() {
+            for j in 0..3 {
+                mat[[i, j]] = axis[j];
+            }
+            mat[[i, 3]] = -axis.dot(eye);
+        }
+        mat
+    }
+
+    fn perspective(fov_y: T, aspect: T, near: T, far: T, rh: bool, zo: bool) -> Self {
+        let two = T::one() + T::one();
+        let (sin, cos) = (fov_y / two).sin_cos();
+        let focal = cos / sin;
+        // view space depth of visible points, positive in front of the camera
+        let direction = if rh { -T::one() } else { T::one() };
+
+        let mut mat = Self::default();
+        mat[[0, 0]] = focal / aspect;
+        mat[[1, 1]] = focal;
+        mat[[3, 2]] = direction;
+        if zo {
+            mat[[2, 2]] = direction * far / (far - near);
+            mat[[2, 3]] = -(far * near) / (far - near);
+        } else {
+            mat[[2, 2]] = direction * (far + near) / (far - near);
+            mat[[2, 3]] = -(two * far * near) / (far - near);
+        }
+        mat
+    }
+
+    fn orthographic(bounds: [T; 4], near: T, far: T, rh: bool, zo: bool) -> Self {
+        let two = T::one() + T::one();
+        let [left, right, bottom, top] = bounds;
+        let direction = if rh { -T::one() } else { T::one() };
+
+        let mut mat = Self::mul_identity();
+        mat[[0, 0]] = two / (right - left);
+        mat[[1, 1]] = two / (top - bottom);
+        mat[[0, 3]] = -(right + left) / (right - left);
+        mat[[1, 3]] = -(top + bottom) / (top - bottom);
+        if zo {
+            mat[[2, 2]] = direction / (far - near);
+            mat[[2, 3]] = -near / (far - near);
+        } else {
+            mat[[2, 2]] = direction * two / (far - near);
+            mat[[2, 3]] = -(far + near) / (far - near);
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps> Vector<T, 3> {
+    /// transforms the point `(x, y, z, 1)` and divides by the resulting `w`
+    #[inline]
+    pub fn transform_point(&self, transform: &SquareMatrix<T, 4>) -> Self {
+        let point = *transform * Vector::from([self[0], self[1], self[2], T::one()]);
+        Vector::from([
+            point[0] / point[3],
+            point[1] / point[3],
+            point[2] / point[3],
+        ])
+    }
+
+    /// transforms the direction `(x, y, z, 0)`, which is unaffected by translations
+    #[inline]
+    pub fn transform_vector(&self, transform: &SquareMatrix<T, 4>) -> Self {
+        let vector = *transform * Vector::from([self[0], self[1], self[2], T::zero()]);
+        Vector::from([vector[0], vector[1], vector[2]])
+    }
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use crate::algebra::linear::{SquareMatrix, Vector};
+    use crate::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn assert_close(lhs: Vector<f64, 3>, rhs: [f64; 3]) {
+        assert_approx_eq!(lhs, Vector::from(rhs), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn affine() {
+        let point = Vector::from([1.0, 2.0, 3.0]);
+        let translation = SquareMatrix::from_translation(Vector::from([1.0, -1.0, 2.0]));
+        assert_close(point.transform_point(&translation), [2.0, 1.0, 5.0]);
+        assert_close(point.transform_vector(&translation), [1.0, 2.0, 3.0]);
+
+        let scale = SquareMatrix::from_scale(Vector::from([2.0, 3.0, -1.0]));
+        assert_close(point.transform_point(&scale), [2.0, 6.0, -3.0]);
+
+        let rotation = SquareMatrix::from_axis_angle(Vector::from([0.0, 0.0, 2.0]), FRAC_PI_2);
+        assert_close(point.transform_point(&rotation), [-2.0, 1.0, 3.0]);
+        let rotation = SquareMatrix::from_axis_angle(Vector::from([1.0, 0.0, 0.0]), FRAC_PI_2);
+        assert_close(point.transform_vector(&rotation), [1.0, -3.0, 2.0]);
+    }
+
+    #[test]
+    fn look_at() {
+        let eye = Vector::from([1.0, 2.0, 3.0]);
+        let target = Vector::from([1.0, 2.0, -1.0]);
+        let up = Vector::from([0.0, 1.0, 0.0]);
+
+        let view = SquareMatrix::look_at_rh(eye, target, up);
+        assert_close(eye.transform_point(&view), [0.0, 0.0, 0.0]);
+        assert_close(target.transform_point(&view), [0.0, 0.0, -4.0]);
+        assert_close(up.transform_vector(&view), [0.0, 1.0, 0.0]);
+
+        let view = SquareMatrix::look_at_lh(eye, target, up);
+        assert_close(eye.transform_point(&view), [0.0, 0.0, 0.0]);
+        assert_close(target.transform_point(&view), [0.0, 0.0, 4.0]);
+        assert_close(up.transform_vector(&view), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn perspective() {
+        let (near, far) = (0.5, 10.0);
+        let rh_near = Vector::from([0.0, 0.5, -near]);
+        let rh_far = Vector::from([10.0, 0.0, -far]);
+        let lh_near = Vector::from([0.0, 0.5, near]);
+        let lh_far = Vector::from([10.0, 0.0, far]);
+
+        let proj = SquareMatrix::perspective_rh_zo(FRAC_PI_2, 2.0, near, far);
+        assert_close(rh_near.transform_point(&proj), [0.0, 1.0, 0.0]);
+        assert_close(rh_far.transform_point(&proj), [0.5, 0.0, 1.0]);
+
+        let proj = SquareMatrix::perspective_rh_no(FRAC_PI_2, 2.0, near, far);
+        assert_close(rh_near.transform_point(&proj), [0.0, 1.0, -1.0]);
+        assert_close(rh_far.transform_point(&proj), [0.5, 0.0, 1.0]);
+
+        let proj = SquareMatrix::perspective_lh_zo(FRAC_PI_2, 2.0, near, far);
+        assert_close(lh_near.transform_point(&proj), [0.0, 1.0, 0.0]);
+        assert_close(lh_far.transform_point(&proj), [0.5, 0.0, 1.0]);
+
+        let proj = SquareMatrix::perspective_lh_no(FRAC_PI_2, 2.0, near, far);
+        assert_close(lh_near.transform_point(&proj), [0.0, 1.0, -1.0]);
+        assert_close(lh_far.transform_point(&proj), [0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn orthographic() {
+        let min_rh = Vector::from([-2.0, 0.0, -1.0]);
+        let max_rh = Vector::from([4.0, 3.0, -5.0]);
+        let min_lh = Vector::from([-2.0, 0.0, 1.0]);
+        let max_lh = Vector::from([4.0, 3.0, 5.0]);
+
+        let proj = SquareMatrix::orthographic_rh_zo(-2.0, 4.0, 0.0, 3.0, 1.0, 5.0);
+        assert_close(min_rh.transform_point(&proj), [-1.0, -1.0, 0.0]);
+        assert_close(max_rh.transform_point(&proj), [1.0, 1.0, 1.0]);
+
+        let proj = SquareMatrix::orthographic_rh_no(-2.0, 4.0, 0.0, 3.0, 1.0, 5.0);
+        assert_close(min_rh.transform_point(&proj), [-1.0, -1.0, -1.0]);
+        assert_close(max_rh.transform_point(&proj), [1.0, 1.0, 1.0]);
+
+        let proj = SquareMatrix::orthographic_lh_zo(-2.0, 4.0, 0.0, 3.0, 1.0, 5.0);
+        assert_close(min_lh.transform_point(&proj), [-1.0, -1.0, 0.0]);
+        assert_close(max_lh.transform_point(&proj), [1.0, 1.0, 1.0]);
+
+        let proj = SquareMatrix::orthographic_lh_no(-2.0, 4.0, 0.0, 3.0, 1.0, 5.0);
+        assert_close(min_lh.transform_point(&proj), [-1.0, -1.0, -1.0]);
+        assert_close(max_lh.transform_point(&proj), [1.0, 1.0, 1.0]);
+    }
+}
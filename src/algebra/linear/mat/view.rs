@@ -0,0 +1,329 @@
+//! borrowed rectangular regions of a matrix
+//!
+//! a view keeps the strides of the matrix it borrows from, so taking one never copies.
+//! Arithmetic with a view on the left returns a `DMatrix`, owned matrices on the left keep their type.
+use crate::algebra::linear::{
+    DMatrix, GenericMatrix, Scalar, SliceStorage, SliceStorageMut, Storage, StorageMut,
+};
+use fructose::operators::{ClosedAdd, ClosedMul, ClosedSub};
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Bound, Mul, Range, RangeBounds, Sub, SubAssign};
+
+/// a rectangular region borrowed from a matrix
+pub type MatrixView<'a, T> = GenericMatrix<SliceStorage<'a, T>>;
+
+/// a rectangular region mutably borrowed from a matrix
+pub type MatrixViewMut<'a, T> = GenericMatrix<SliceStorageMut<'a, T>>;
+
+/// start and length of `range` inside of `0..len`, panics if it doesn't fit
+fn resolve<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range is out of bounds");
+    (start, end - start)
+}
+
+/// the part of the buffer of `mat` from entry `[i, j]` up to the last entry of the region,
+/// or an empty range at the end of it for empty regions
+fn bounds<S: Storage>(
+    mat: &GenericMatrix<S>,
+    i: usize,
+    j: usize,
+    shape: (usize, usize),
+) -> Range<usize> {
+    let (rows, columns) = mat.size();
+    assert!(
+        i + shape.0 <= rows && j + shape.1 <= columns,
+        "view is out of bounds"
+    );
+    if shape.0 == 0 || shape.1 == 0 {
+        let len = mat.as_slice().len();
+        return len..len;
+    }
+    let (row_stride, column_stride) = mat.strides();
+    let start = i * row_stride + j * column_stride;
+    let last = (i + shape.0 - 1) * row_stride + (j + shape.1 - 1) * column_stride;
+    start..last + 1
+}
+
+impl<S: Storage> GenericMatrix<S> {
+    /// the `rows`x`columns` block whose upper left entry is at row `i` and column `j`
+    #[inline]
+    pub fn view(
+        &self,
+        i: usize,
+        j: usize,
+        rows: usize,
+        columns: usize,
+    ) -> MatrixView<'_, S::Element> {
+        let range = bounds(self, i, j, (rows, columns));
+        GenericMatrix {
+            data: SliceStorage::with_strides(
+                &self.as_slice()[range],
+                (rows, columns),
+                self.strides(),
+            ),
+        }
+    }
+
+    /// the whole matrix as a view
+    #[inline]
+    pub fn as_view(&self) -> MatrixView<'_, S::Element> {
+        let (rows, columns) = self.size();
+        self.view(0, 0, rows, columns)
+    }
+
+    /// the rows in `range` with all of their columns
+    #[inline]
+    pub fn rows<R: RangeBounds<usize>>(&self, range: R) -> MatrixView<'_, S::Element> {
+        let (rows, columns) = self.size();
+        let (start, len) = resolve(range, rows);
+        self.view(start, 0, len, columns)
+    }
+
+    /// the columns in `range` with all of their rows
+    #[inline]
+    pub fn columns<R: RangeBounds<usize>>(&self, range: R) -> MatrixView<'_, S::Element> {
+        let (rows, columns) = self.size();
+        let (start, len) = resolve(range, columns);
+        self.view(0, start, rows, len)
+    }
+}
+
+impl<S: StorageMut> GenericMatrix<S> {
+    /// the `rows`x`columns` block whose upper left entry is at row `i` and column `j`
+    #[inline]
+    pub fn view_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+        rows: usize,
+        columns: usize,
+    ) -> MatrixViewMut<'_, S::Element> {
+        let range = bounds(self, i, j, (rows, columns));
+        let strides = self.strides();
+        GenericMatrix {
+            data: SliceStorageMut::with_strides(
+                &mut self.as_slice_mut()[range],
+                (rows, columns),
+                strides,
+            ),
+        }
+    }
+
+    /// the whole matrix as a mutable view
+    #[inline]
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, S::Element> {
+        let (rows, columns) = self.size();
+        self.view_mut(0, 0, rows, columns)
+    }
+
+    /// the rows in `range` with all of their columns
+    #[inline]
+    pub fn rows_mut<R: RangeBounds<usize>>(&mut self, range: R) -> MatrixViewMut<'_, S::Element> {
+        let (rows, columns) = self.size();
+        let (start, len) = resolve(range, rows);
+        self.view_mut(start, 0, len, columns)
+    }
+
+    /// the columns in `range` with all of their rows
+    #[inline]
+    pub fn columns_mut<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> MatrixViewMut<'_, S::Element> {
+        let (rows, columns) = self.size();
+        let (start, len) = resolve(range, columns);
+        self.view_mut(0, start, rows, len)
+    }
+}
+
+impl<T: Scalar, S: StorageMut<Element = T>> GenericMatrix<S> {
+    /// overwrites every entry with the one at the same position in `other`, the shapes have to match
+    pub fn copy_from<R: Storage<Element = T>>(&mut self, other: &GenericMatrix<R>) {
+        assert_eq!(self.size(), other.size());
+        let (rows, columns) = self.size();
+        for n in 0..columns {
+            for m in 0..rows {
+                self[[m, n]] = other[[m, n]];
+            }
+        }
+    }
+
+    #[inline]
+    pub fn fill(&mut self, value: T) {
+        self.apply(|_| value);
+    }
+}
+
+macro_rules! impl_view_ops {
+    ($($view:ident, $rhs:ty, <$($lifetime:lifetime),*>);*) => {
+        $(
+            impl<'a, $($lifetime,)* T: Scalar + ClosedAdd, R: Storage<Element = T>> Add<$rhs>
+                for $view<'a, T>
+            {
+                type Output = DMatrix<T>;
+
+                #[inline]
+                fn add(self, rhs: $rhs) -> Self::Output {
+                    let mut mat = self.clone_owned();
+                    mat += rhs.borrow();
+                    mat
+                }
+            }
+
+            impl<'a, $($lifetime,)* T: Scalar + ClosedSub, R: Storage<Element = T>> Sub<$rhs>
+                for $view<'a, T>
+            {
+                type Output = DMatrix<T>;
+
+                #[inline]
+                fn sub(self, rhs: $rhs) -> Self::Output {
+                    let mut mat = self.clone_owned();
+                    mat -= rhs.borrow();
+                    mat
+                }
+            }
+
+            impl<'a, $($lifetime,)* T: Scalar + ClosedAdd + ClosedMul, R: Storage<Element = T>>
+                Mul<$rhs> for $view<'a, T>
+            {
+                type Output = DMatrix<T>;
+
+                #[inline]
+                fn mul(self, rhs: $rhs) -> Self::Output {
+                    let mut mat = DMatrix::default_with_size((self.size().0, rhs.size().1));
+                    self.mul_to(rhs.borrow(), &mut mat);
+                    mat
+                }
+            }
+        )*
+    };
+}
+
+impl_view_ops!(
+    MatrixView, GenericMatrix<R>, <>;
+    MatrixView, &'r GenericMatrix<R>, <'r>;
+    MatrixViewMut, GenericMatrix<R>, <>;
+    MatrixViewMut, &'r GenericMatrix<R>, <'r>
+);
+
+impl<'a, T: Scalar + ClosedAdd, R: Storage<Element = T>> AddAssign<GenericMatrix<R>>
+    for MatrixViewMut<'a, T>
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: GenericMatrix<R>) {
+        *self += &rhs;
+    }
+}
+
+impl<'a, T: Scalar + ClosedSub, R: Storage<Element = T>> SubAssign<GenericMatrix<R>>
+    for MatrixViewMut<'a, T>
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: GenericMatrix<R>) {
+        *self -= &rhs;
+    }
+}
+
+#[cfg(test)]
+mod view_tests {
+    use crate::algebra::linear::{
+        DMatrix, GenericMatrix, Matrix, MatrixView, SliceStorage, SliceStorageMut,
+    };
+
+    #[test]
+    fn slicing() {
+        let mat = DMatrix::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        assert_eq!(
+            mat.rows(1..).clone_owned(),
+            DMatrix::new(vec![vec![2, 3], vec![5, 6], vec![8, 9]])
+        );
+        assert_eq!(
+            mat.columns(..=1).clone_owned(),
+            DMatrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]])
+        );
+
+        let block = mat.view(1, 1, 2, 2);
+        assert_eq!(block.strides(), (1, 3));
+        assert_eq!(
+            block.clone_owned(),
+            DMatrix::new(vec![vec![5, 6], vec![8, 9]])
+        );
+        assert_eq!(
+            block.rows(1..2).clone_owned(),
+            DMatrix::new(vec![vec![6], vec![9]])
+        );
+        assert_eq!(block.view(1, 1, 0, 1).size(), (0, 1));
+
+        let fixed = Matrix::<i32, 3, 3>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(fixed.view(1, 1, 2, 2).clone_owned(), block.clone_owned());
+        assert_eq!(fixed.transpose_view().columns(2..)[[1, 0]], 6);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut mat = DMatrix::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let identity = Matrix::<f64, 2, 2>::new([[1.0, 0.0], [0.0, 1.0]]);
+
+        assert_eq!(mat.as_view() * identity.as_view(), mat);
+        assert_eq!(
+            mat.rows(..1) + mat.rows(1..),
+            DMatrix::new(vec![vec![3.0], vec![7.0]])
+        );
+        assert_eq!(
+            mat.as_view() - identity,
+            DMatrix::new(vec![vec![0.0, 2.0], vec![3.0, 3.0]])
+        );
+        assert_eq!(
+            identity + &mat.transpose_view(),
+            Matrix::new([[2.0, 3.0], [2.0, 5.0]])
+        );
+        assert_eq!(mat.clone() * &identity, mat);
+        assert_eq!(mat.as_view() * &mat.clone(), mat.clone() * mat.clone());
+
+        let copy = mat.clone();
+        mat.columns_mut(1..).copy_from(&copy.columns(..1));
+        mat.rows_mut(..1).fill(0.0);
+        mat.view_mut(1, 0, 1, 2).transpose_view_mut()[[1, 0]] += 10.0;
+        let mut view = mat.as_view_mut();
+        view += copy.transpose_view();
+        view -= &DMatrix::from_element((2, 2), 0.0);
+        assert_eq!(mat, DMatrix::new(vec![vec![1.0, 5.0], vec![2.0, 16.0]]));
+    }
+
+    #[test]
+    fn assign_leaves_the_rest() {
+        let mut a = DMatrix::new(vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+        let b = DMatrix::new(vec![vec![10, 20], vec![30, 40], vec![50, 60]]);
+        let mut column = a.columns_mut(0..1);
+        column += &b.columns(0..1);
+        assert_eq!(a.as_slice(), [11, 22, 3, 4, 5, 6]);
+
+        // a borrowed buffer longer than the entries has to be left alone as well
+        let mut buffer = [1, 2, 3, 4];
+        let mut long = GenericMatrix {
+            data: SliceStorageMut::with_strides(&mut buffer, (2, 1), (1, 2)),
+        };
+        let rhs = GenericMatrix {
+            data: SliceStorage::with_strides(b.as_slice(), (2, 1), (1, 2)),
+        };
+        long -= &rhs;
+        assert_eq!(buffer, [-9, -18, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        let mat = DMatrix::new(vec![vec![1, 2], vec![3, 4]]);
+        let _: MatrixView<i32> = mat.view(1, 0, 2, 1);
+    }
+}
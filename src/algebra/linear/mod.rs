@@ -1,15 +1,40 @@
-//! Bivectors and Roters are not abstracted over their dimension because I don't know how
-//! I will probably fix this in the future!
+//! Bivectors and Rotors are implemented for 2D and 3D separately,
+//! `Multivector` covers every dimension and metric signature but stores its components on the heap,
+//! the 2D and 3D types convert into the multivectors of the euclidean plane and space.
+//!
+//! `Matrix` and `DMatrix` are both a `GenericMatrix`, one backed by arrays and the other by a `Vec`.
+//! `CooMatrix`, `CsrMatrix` and `CscMatrix` only store the nonzero entries of big sparse matrices.
+mod approx;
 mod bivec;
+mod cast;
+mod dual_quaternion;
 mod dynamic;
+mod euler;
 mod mat;
+mod multivector;
+mod parse;
+pub mod pga;
+mod quaternion;
 mod rotor;
 mod scalar;
+mod sparse;
+mod storage;
 mod vec;
 
+pub use approx::ApproxEq;
 pub use bivec::*;
+pub use cast::{CheckedCast, LossyCast};
+pub use dual_quaternion::*;
 pub use dynamic::*;
+pub use euler::{EulerAngles, EulerFrame, EulerOrder};
 pub use mat::*;
+pub use multivector::Multivector;
+pub use parse::ParseMatrixError;
+pub use quaternion::*;
 pub use rotor::*;
 pub use scalar::Scalar;
+pub use sparse::{CooMatrix, CscMatrix, CsrMatrix};
+pub use storage::{
+    ArrayStorage, OwnedStorage, SliceStorage, SliceStorageMut, Storage, StorageMut, VecStorage,
+};
 pub use vec::*;
@@ -0,0 +1,627 @@
+use crate::algebra::linear::bivec::{Bivector2, Bivector3};
+use crate::algebra::linear::rotor::{Rotor2, Rotor3};
+use crate::algebra::linear::scalar::Scalar;
+use fructose::operators::{ClosedNeg, ClosedOps};
+use fructose::properties::helpers::float::Float;
+use fructose::specific::complex::Real;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Index, IndexMut, Mul, Neg, Sub};
+
+/// element of the clifford algebra `Cl(P, Q, R)`
+///
+/// the first `P` basis vectors square to `1`, the next `Q` to `-1` and the last `R` to `0`.
+/// the `2^(P + Q + R)` components are indexed by blade bitmasks,
+/// bit `i` set means `e_i` is a factor, so `data[0b011]` is the coefficient of `e_0 ∧ e_1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Multivector<T, const P: usize, const Q: usize, const R: usize> {
+    pub data: Vec<T>,
+}
+
+/// number of swaps needed to sort the basis vectors of `a * b` is odd
+fn reordering_is_odd(a: usize, b: usize) -> bool {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+    swaps % 2 == 1
+}
+
+impl<T: Scalar, const P: usize, const Q: usize, const R: usize>
+    Multivector<T, { P }, { Q }, { R }>
+{
+    /// number of basis vectors
+    pub const DIMENSION: usize = P + Q + R;
+
+    /// number of components, one per basis blade
+    pub const LEN: usize = 1 << (P + Q + R);
+
+    /// bitmask of the pseudoscalar
+    const PSEUDOSCALAR: usize = (1 << (P + Q + R)) - 1;
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            data: vec![T::default(); Self::LEN],
+        }
+    }
+
+    /// the multivector `value * e_blade`
+    #[inline]
+    pub fn blade(blade: usize, value: T) -> Self {
+        let mut mv = Self::zero();
+        mv[blade] = value;
+        mv
+    }
+
+    #[inline]
+    pub fn scalar(value: T) -> Self {
+        Self::blade(0, value)
+    }
+
+    /// the vector `sum(components[i] * e_i)`
+    #[inline]
+    pub fn from_vector(components: &[T]) -> Self {
+        assert_eq!(
+            components.len(),
+            Self::DIMENSION,
+            "wrong number of components"
+        );
+        let mut mv = Self::zero();
+        for (i, c) in components.iter().enumerate() {
+            mv[1 << i] = *c;
+        }
+        mv
+    }
+
+    /// the coefficients of the basis vectors
+    #[inline]
+    pub fn vector_part(&self) -> Vec<T> {
+        (0..Self::DIMENSION).map(|i| self[1 << i]).collect()
+    }
+
+    /// keeps only the components of grade `k`
+    #[inline]
+    pub fn grade(&self, k: usize) -> Self {
+        let mut mv = Self::zero();
+        for blade in (0..Self::LEN).filter(|b| b.count_ones() as usize == k) {
+            mv[blade] = self[blade];
+        }
+        mv
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg, const P: usize, const Q: usize, const R: usize>
+    Multivector<T, { P }, { Q }, { R }>
+{
+    /// the sign of `e_a * e_b`, `None` if it is zero because a shared basis vector squares to zero
+    fn blade_product_is_negative(a: usize, b: usize) -> Option<bool> {
+        let mut negative = reordering_is_odd(a, b);
+        let common = a & b;
+        for i in (0..Self::DIMENSION).filter(|i| common & (1 << i) != 0) {
+            if i >= P + Q {
+                return None;
+            }
+            if i >= P {
+                negative = !negative;
+            }
+        }
+        Some(negative)
+    }
+
+    /// sums the products of all pairs of blades accepted by `keep`
+    fn product(&self, rhs: &Self, keep: impl Fn(usize, usize) -> bool) -> Self {
+        let mut mv = Self::zero();
+        for a in 0..Self::LEN {
+            for b in (0..Self::LEN).filter(|b| keep(a, *b)) {
+                match Self::blade_product_is_negative(a, b) {
+                    Some(false) => mv[a ^ b] += self[a] * rhs[b],
+                    Some(true) => mv[a ^ b] -= self[a] * rhs[b],
+                    None => {}
+                }
+            }
+        }
+        mv
+    }
+
+    #[inline]
+    pub fn geometric(&self, rhs: &Self) -> Self {
+        self.product(rhs, |_, _| true)
+    }
+
+    /// exterior product, the span of both operands
+    #[inline]
+    pub fn outer(&self, rhs: &Self) -> Self {
+        self.product(rhs, |a, b| a & b == 0)
+    }
+
+    /// grade `|k - l|` part of the geometric product of the grade `k` and `l` parts
+    #[inline]
+    pub fn inner(&self, rhs: &Self) -> Self {
+        self.product(rhs, |a, b| a & b == a || a & b == b)
+    }
+
+    /// grade `l - k` part of the geometric product of the grade `k` and `l` parts
+    #[inline]
+    pub fn left_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, |a, b| a & b == a)
+    }
+
+    /// grade `k - l` part of the geometric product of the grade `k` and `l` parts
+    #[inline]
+    pub fn right_contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, |a, b| a & b == b)
+    }
+
+    /// the scalar part of the geometric product
+    #[inline]
+    pub fn scalar_product(&self, rhs: &Self) -> T {
+        self.product(rhs, |a, b| a == b)[0]
+    }
+
+    /// reverses the order of the basis vectors in every blade, this negates grades 2, 3, 6, 7, ...
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut mv = self.clone();
+        for blade in 0..Self::LEN {
+            if (blade.count_ones() / 2) % 2 == 1 {
+                mv[blade] = -mv[blade];
+            }
+        }
+        mv
+    }
+
+    /// negates the odd grades
+    #[inline]
+    pub fn involuted(&self) -> Self {
+        let mut mv = self.clone();
+        for blade in 0..Self::LEN {
+            if blade.count_ones() % 2 == 1 {
+                mv[blade] = -mv[blade];
+            }
+        }
+        mv
+    }
+
+    /// metric independent dual, maps every blade `A` to the blade `B` with `A ∧ B = I`
+    #[inline]
+    pub fn right_complement(&self) -> Self {
+        let mut mv = Self::zero();
+        for blade in 0..Self::LEN {
+            let complement = Self::PSEUDOSCALAR ^ blade;
+            mv[complement] = if reordering_is_odd(blade, complement) {
+                -self[blade]
+            } else {
+                self[blade]
+            };
+        }
+        mv
+    }
+
+    /// inverse of `right_complement`, maps every blade `A` to the blade `B` with `B ∧ A = I`
+    #[inline]
+    pub fn left_complement(&self) -> Self {
+        let mut mv = Self::zero();
+        for blade in 0..Self::LEN {
+            let complement = Self::PSEUDOSCALAR ^ blade;
+            mv[complement] = if reordering_is_odd(complement, blade) {
+                -self[blade]
+            } else {
+                self[blade]
+            };
+        }
+        mv
+    }
+
+    /// hodge star, defined by `A ∧ ⋆B = <A~ B> I`
+    ///
+    /// blades containing a degenerate basis vector are mapped to zero,
+    /// use the complements for a dual that works in degenerate metrics.
+    #[inline]
+    pub fn hodge_dual(&self) -> Self {
+        let mut mv = Self::zero();
+        for blade in 0..Self::LEN {
+            if let Some(negative) = Self::blade_product_is_negative(blade, blade) {
+                // e_A~ e_A is the product of the squares, e_A e_A additionally reverses
+                let reversal_is_odd = (blade.count_ones() / 2) % 2 == 1;
+                let value = if negative != reversal_is_odd {
+                    -self[blade]
+                } else {
+                    self[blade]
+                };
+                mv[blade] = value;
+            }
+        }
+        mv.right_complement()
+    }
+
+    /// regressive product, the intersection of both operands
+    #[inline]
+    pub fn regressive(&self, rhs: &Self) -> Self {
+        self.right_complement()
+            .outer(&rhs.right_complement())
+            .left_complement()
+    }
+
+    /// applies a versor: `self * x * self~`
+    #[inline]
+    pub fn sandwich(&self, x: &Self) -> Self {
+        self.geometric(x).geometric(&self.reversed())
+    }
+}
+
+impl<
+        T: Scalar + Real + ClosedOps + ClosedNeg + Float,
+        const P: usize,
+        const Q: usize,
+        const R: usize,
+    > Multivector<T, { P }, { Q }, { R }>
+{
+    /// exponential, a bivector `B` turns into the rotor `exp(B)`
+    ///
+    /// closed form if the square is a scalar, which holds for all bivectors up to 3 dimensions
+    /// and all simple bivectors, the power series otherwise.
+    /// a rotor rotating by `angle` in the plane `e_i ∧ e_j` is `exp(-angle / 2 * e_i ∧ e_j)`.
+    pub fn exp(&self) -> Self {
+        let square = self.geometric(self);
+        let mut scale = T::zero();
+        let mut off_scalar = T::zero();
+        for blade in 0..Self::LEN {
+            scale += square[blade].abs();
+            if blade != 0 {
+                off_scalar += square[blade].abs();
+            }
+        }
+
+        if off_scalar > scale * <T as Float>::EPSILON * (T::one() + T::one()) {
+            return self.exp_series();
+        }
+
+        let s = square[0];
+        let (even, odd) = if s.abs() <= <T as Float>::EPSILON {
+            (T::one(), T::one())
+        } else if s < T::zero() {
+            let alpha = (-s).sqrt();
+            (alpha.cos(), alpha.sin() / alpha)
+        } else {
+            let alpha = s.sqrt();
+            (alpha.cosh(), alpha.sinh() / alpha)
+        };
+
+        let mut mv = self.clone();
+        for blade in 0..Self::LEN {
+            mv[blade] *= odd;
+        }
+        mv[0] += even;
+        mv
+    }
+
+    /// taylor series with scaling and squaring
+    fn exp_series(&self) -> Self {
+        let two = T::one() + T::one();
+        let half = T::one() / two;
+        let mut norm = T::zero();
+        self.data.iter().for_each(|e| norm += e.abs());
+
+        let mut scaled = self.clone();
+        let mut squarings = 0;
+        while norm > half {
+            norm *= half;
+            for e in scaled.data.iter_mut() {
+                *e *= half;
+            }
+            squarings += 1;
+        }
+
+        let mut sum = Self::scalar(T::one());
+        let mut term = Self::scalar(T::one());
+        let mut k = T::zero();
+        for _ in 0..32 {
+            k += T::one();
+            term = term.geometric(&scaled);
+            for e in term.data.iter_mut() {
+                *e /= k;
+            }
+            let mut size = T::zero();
+            term.data.iter().for_each(|e| size += e.abs());
+            sum = sum + term.clone();
+            if size <= <T as Float>::EPSILON {
+                break;
+            }
+        }
+
+        for _ in 0..squarings {
+            sum = sum.geometric(&sum);
+        }
+        sum
+    }
+}
+
+impl<T, const P: usize, const Q: usize, const R: usize> Index<usize>
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = T;
+
+    /// the coefficient of the blade with bitmask `index`
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.data[index]
+    }
+}
+
+impl<T, const P: usize, const Q: usize, const R: usize> IndexMut<usize>
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.data[index]
+    }
+}
+
+impl<T: Scalar + ClosedOps, const P: usize, const Q: usize, const R: usize> Add
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(a, b)| *a += b);
+        self
+    }
+}
+
+impl<T: Scalar + ClosedOps, const P: usize, const Q: usize, const R: usize> Sub
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.data
+            .iter_mut()
+            .zip(rhs.data)
+            .for_each(|(a, b)| *a -= b);
+        self
+    }
+}
+
+impl<T: Scalar + ClosedNeg, const P: usize, const Q: usize, const R: usize> Neg
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        self.data.iter_mut().for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<T: Scalar + ClosedOps, const P: usize, const Q: usize, const R: usize> Mul<T>
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|e| *e *= rhs);
+        self
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg, const P: usize, const Q: usize, const R: usize> Mul
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    /// geometric product
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.geometric(&rhs)
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg, const P: usize, const Q: usize, const R: usize> BitXor
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    /// outer product
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        self.outer(&rhs)
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg, const P: usize, const Q: usize, const R: usize> BitOr
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    /// inner product
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.inner(&rhs)
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg, const P: usize, const Q: usize, const R: usize> BitAnd
+    for Multivector<T, { P }, { Q }, { R }>
+{
+    type Output = Self;
+
+    /// regressive product
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.regressive(&rhs)
+    }
+}
+
+// the 2D and 3D types are the even subalgebras of the euclidean multivectors, xy = e_0 ∧ e_1
+
+impl<T: Scalar> From<Bivector2<T>> for Multivector<T, 2, 0, 0> {
+    fn from(rhs: Bivector2<T>) -> Self {
+        Self::blade(0b11, rhs.data)
+    }
+}
+
+impl<T: Scalar> From<Rotor2<T>> for Multivector<T, 2, 0, 0> {
+    fn from(rhs: Rotor2<T>) -> Self {
+        let mut mv = Self::from(rhs.bivector);
+        mv[0] = rhs.scalar;
+        mv
+    }
+}
+
+impl<T: Scalar> From<Multivector<T, 2, 0, 0>> for Rotor2<T> {
+    /// the even part of `rhs`
+    fn from(rhs: Multivector<T, 2, 0, 0>) -> Self {
+        Rotor2::new(rhs[0], Bivector2::new(rhs[0b11]))
+    }
+}
+
+impl<T: Scalar> From<Bivector3<T>> for Multivector<T, 3, 0, 0> {
+    fn from(rhs: Bivector3<T>) -> Self {
+        let mut mv = Self::zero();
+        mv[0b011] = rhs.xy;
+        mv[0b101] = rhs.xz;
+        mv[0b110] = rhs.yz;
+        mv
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for Multivector<T, 3, 0, 0> {
+    fn from(rhs: Rotor3<T>) -> Self {
+        let mut mv = Self::from(rhs.bivector);
+        mv[0] = rhs.scalar;
+        mv
+    }
+}
+
+impl<T: Scalar> From<Multivector<T, 3, 0, 0>> for Rotor3<T> {
+    /// the even part of `rhs` without the pseudoscalar
+    fn from(rhs: Multivector<T, 3, 0, 0>) -> Self {
+        Rotor3::new(rhs[0], Bivector3::new(rhs[0b011], rhs[0b101], rhs[0b110]))
+    }
+}
+
+#[cfg(test)]
+mod multivector_tests {
+    use crate::algebra::linear::{Bivector3, Multivector, Rotor2, Rotor3, Vector};
+    use crate::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    type Cl3 = Multivector<f64, 3, 0, 0>;
+
+    fn e(i: usize) -> Cl3 {
+        Cl3::blade(1 << i, 1.0)
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(e(0) * e(1), Cl3::blade(0b011, 1.0));
+        assert_eq!(e(1) * e(0), Cl3::blade(0b011, -1.0));
+        assert_eq!(e(0) * e(0), Cl3::scalar(1.0));
+        let pseudoscalar = e(0) * e(1) * e(2);
+        assert_eq!(pseudoscalar.clone() * pseudoscalar, Cl3::scalar(-1.0));
+
+        let a = Cl3::from_vector(&[1.0, 2.0, 3.0]);
+        let b = Cl3::from_vector(&[-1.0, 0.5, 2.0]);
+        assert_eq!(a.clone() ^ a.clone(), Cl3::zero());
+        assert_eq!(a.clone() ^ b.clone(), -(b.clone() ^ a.clone()));
+        // a * b = a · b + a ∧ b
+        assert_eq!(
+            a.clone() * b.clone(),
+            (a.clone() | b.clone()) + (a.clone() ^ b.clone())
+        );
+        assert_eq!((a.clone() | b.clone())[0], 6.0);
+
+        // e1 ⌋ e12 = e2, e12 ⌋ e1 = 0
+        let e01 = e(0) ^ e(1);
+        assert_eq!(e(0).left_contraction(&e01), e(1));
+        assert_eq!(e01.left_contraction(&e(0)), Cl3::zero());
+        assert_eq!(e01.right_contraction(&e(1)), e(0));
+
+        // the planes xy and yz meet in the y axis
+        let e12 = e(1) ^ e(2);
+        assert_eq!(e01.clone() & e12, e(1));
+        assert_eq!(e01.right_complement().left_complement(), e01);
+    }
+
+    #[test]
+    fn signatures() {
+        let e = |i: usize| Multivector::<f64, 1, 1, 1>::blade(1 << i, 1.0);
+        assert_eq!((e(0) * e(0))[0], 1.0);
+        assert_eq!((e(1) * e(1))[0], -1.0);
+        assert_eq!(e(2) * e(2), Multivector::zero());
+        assert_eq!(e(2).hodge_dual(), Multivector::zero());
+        assert_eq!(e(0).hodge_dual(), e(1) ^ e(2));
+        assert_eq!(Multivector::<f64, 1, 1, 1>::LEN, 8);
+    }
+
+    #[test]
+    fn grades_and_duals() {
+        let mv = Cl3 {
+            data: (1..=8).map(f64::from).collect(),
+        };
+        assert_eq!(mv.grade(0), Cl3::scalar(1.0));
+        assert_eq!(mv.grade(3), Cl3::blade(0b111, 8.0));
+        assert_eq!(
+            mv.grade(1) + mv.grade(2),
+            mv.clone() - mv.grade(0) - mv.grade(3)
+        );
+        assert_eq!(mv.reversed().grade(2), -mv.grade(2));
+        assert_eq!(mv.reversed().grade(1), mv.grade(1));
+        assert_eq!(mv.involuted().grade(3), -mv.grade(3));
+
+        assert_eq!(e(0).hodge_dual(), e(1) ^ e(2));
+        assert_eq!(e(1).hodge_dual(), e(2) ^ e(0));
+        assert_eq!(Cl3::scalar(2.0).hodge_dual(), Cl3::blade(0b111, 2.0));
+        assert_eq!((e(0) ^ e(1)).hodge_dual(), e(2));
+    }
+
+    #[test]
+    fn rotors() {
+        let angle = 0.7;
+        let plane = Bivector3::new(0.6, 0.0, 0.8);
+        let rotor = (Cl3::from(plane) * (-angle / 2.0)).exp();
+        let expected = Rotor3::from_angle_plane(angle, plane);
+        assert_approx_eq!(Rotor3::from(rotor.clone()), expected, epsilon = 1e-12);
+
+        let vec = Vector::from([1.0, -2.0, 0.5]);
+        let rotated = rotor.sandwich(&Cl3::from_vector(vec.as_slice()));
+        assert_approx_eq!(
+            Vector::from([rotated[1], rotated[2], rotated[4]]),
+            expected * vec,
+            epsilon = 1e-12
+        );
+
+        let rotor2 = Rotor2::from_angle(FRAC_PI_2);
+        let mv = Multivector::<f64, 2, 0, 0>::from(rotor2);
+        let x = Multivector::<f64, 2, 0, 0>::from_vector(&[1.0, 0.0]);
+        assert_approx_eq!(Rotor2::from(mv.clone()), rotor2);
+        assert_approx_eq!(
+            mv.sandwich(&x),
+            Multivector::from_vector(&[0.0, 1.0]),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn rotor_4d() {
+        // a double rotation in two orthogonal planes, its bivector is not simple
+        type Cl4 = Multivector<f64, 4, 0, 0>;
+        let mut bivector = Cl4::zero();
+        bivector[0b0011] = -0.4;
+        bivector[0b1100] = -1.1;
+        let rotor = bivector.exp();
+
+        let mut expected = Cl4::scalar(0.4f64.cos() * 1.1f64.cos());
+        expected[0b0011] = -0.4f64.sin() * 1.1f64.cos();
+        expected[0b1100] = -1.1f64.sin() * 0.4f64.cos();
+        expected[0b1111] = 0.4f64.sin() * 1.1f64.sin();
+        assert_approx_eq!(rotor, expected, epsilon = 1e-12);
+        assert_approx_eq!(
+            rotor.clone() * rotor.reversed(),
+            Cl4::scalar(1.0),
+            epsilon = 1e-12
+        );
+
+        let rotated = rotor.sandwich(&Cl4::from_vector(&[1.0, 0.0, 1.0, 0.0]));
+        let expected = [0.8f64.cos(), 0.8f64.sin(), 2.2f64.cos(), 2.2f64.sin()];
+        assert_approx_eq!(rotated, Cl4::from_vector(&expected), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,395 @@
+use crate::algebra::linear::{DMatrix, DVector, Matrix};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// error returned when parsing a `Matrix`, `DMatrix` or `DVector` from a string fails
+///
+/// rows and columns are the position inside of the parsed matrix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMatrixError {
+    InvalidToken {
+        row: usize,
+        column: usize,
+        token: String,
+    },
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    RaggedColumn {
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    NotAVector {
+        rows: usize,
+        columns: usize,
+    },
+    UnexpectedCharacter {
+        position: usize,
+        character: char,
+    },
+    UnbalancedBrackets,
+}
+
+impl Display for ParseMatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMatrixError::InvalidToken { row, column, token } => write!(
+                f,
+                "invalid entry `{}` at row {}, column {}",
+                token, row, column
+            ),
+            ParseMatrixError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} entries, expected {}",
+                row, found, expected
+            ),
+            ParseMatrixError::RaggedColumn {
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "column {} has {} entries, expected {}",
+                column, found, expected
+            ),
+            ParseMatrixError::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} matrix, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            ParseMatrixError::NotAVector { rows, columns } => write!(
+                f,
+                "expected a single row or column, found {}x{}",
+                rows, columns
+            ),
+            ParseMatrixError::UnexpectedCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "unexpected character `{}` at position {}",
+                character, position
+            ),
+            ParseMatrixError::UnbalancedBrackets => write!(f, "unbalanced brackets"),
+        }
+    }
+}
+
+impl Error for ParseMatrixError {}
+
+/// a parsed matrix, stored column by column
+struct Columns<T> {
+    rows: usize,
+    columns: Vec<Vec<T>>,
+}
+
+/// splits a list of entries separated by whitespace and/or commas
+fn tokens(list: &str) -> Vec<&str> {
+    if !list.contains(',') {
+        return list.split_whitespace().collect();
+    }
+    let mut tokens = Vec::new();
+    for piece in list.split(',') {
+        if piece.trim().is_empty() {
+            // keep the hole so it gets reported as an invalid token
+            tokens.push("");
+        }
+        tokens.extend(piece.split_whitespace());
+    }
+    tokens
+}
+
+fn parse_token<T: FromStr>(token: &str, row: usize, column: usize) -> Result<T, ParseMatrixError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseMatrixError::InvalidToken {
+            row,
+            column,
+            token: token.to_string(),
+        })
+}
+
+/// parses rows of tokens into columns, all rows need to have the same length
+fn from_rows<T: FromStr>(rows: Vec<Vec<&str>>) -> Result<Columns<T>, ParseMatrixError> {
+    let width = rows.first().map_or(0, |row| row.len());
+    let mut columns: Vec<Vec<T>> = (0..width).map(|_| Vec::with_capacity(rows.len())).collect();
+
+    for (m, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            return Err(ParseMatrixError::RaggedRow {
+                row: m,
+                expected: width,
+                found: row.len(),
+            });
+        }
+        for (n, token) in row.iter().enumerate() {
+            columns[n].push(parse_token(token, m, n)?);
+        }
+    }
+
+    Ok(Columns {
+        rows: rows.len(),
+        columns,
+    })
+}
+
+/// `1 2;3 4`: groups separated by `;` are columns
+fn parse_bare<T: FromStr>(s: &str) -> Result<Columns<T>, ParseMatrixError> {
+    if s.is_empty() {
+        return Ok(Columns {
+            rows: 0,
+            columns: Vec::new(),
+        });
+    }
+
+    let groups: Vec<Vec<&str>> = s.split(';').map(tokens).collect();
+    let height = groups[0].len();
+    let mut columns = Vec::with_capacity(groups.len());
+    for (n, group) in groups.iter().enumerate() {
+        if group.len() != height {
+            return Err(ParseMatrixError::RaggedColumn {
+                column: n,
+                expected: height,
+                found: group.len(),
+            });
+        }
+        let column = group
+            .iter()
+            .enumerate()
+            .map(|(m, token)| parse_token(token, m, n))
+            .collect::<Result<Vec<T>, _>>()?;
+        columns.push(column);
+    }
+
+    Ok(Columns {
+        rows: height,
+        columns,
+    })
+}
+
+/// `[1 2; 3 4]`: rows are separated by `;`
+fn parse_matlab<T: FromStr>(inner: &str) -> Result<Columns<T>, ParseMatrixError> {
+    if inner.trim().is_empty() {
+        return from_rows(Vec::new());
+    }
+    from_rows(inner.split(';').map(tokens).collect())
+}
+
+/// `[[1, 2], [3, 4]]`: every inner list is a row
+fn parse_numpy<T: FromStr>(inner: &str, offset: usize) -> Result<Columns<T>, ParseMatrixError> {
+    let mut rows = Vec::new();
+    let mut start = None;
+    for (i, c) in inner.char_indices() {
+        match (c, start) {
+            ('[', None) => start = Some(i + 1),
+            (']', Some(s)) => {
+                rows.push(tokens(&inner[s..i]));
+                start = None;
+            }
+            ('[', Some(_)) | (']', None) => return Err(ParseMatrixError::UnbalancedBrackets),
+            (_, Some(_)) => {}
+            (c, None) if c.is_whitespace() || c == ',' => {}
+            (character, None) => {
+                return Err(ParseMatrixError::UnexpectedCharacter {
+                    position: offset + i,
+                    character,
+                })
+            }
+        }
+    }
+    if start.is_some() {
+        return Err(ParseMatrixError::UnbalancedBrackets);
+    }
+    from_rows(rows)
+}
+
+fn parse_columns<T: FromStr>(s: &str) -> Result<Columns<T>, ParseMatrixError> {
+    let trimmed = s.trim();
+    if !trimmed.starts_with('[') {
+        if let Some(position) = trimmed.find(['[', ']']) {
+            let offset = s.len() - s.trim_start().len();
+            return Err(ParseMatrixError::UnexpectedCharacter {
+                position: offset + position,
+                character: trimmed[position..].chars().next().unwrap(),
+            });
+        }
+        return parse_bare(trimmed);
+    }
+
+    if !trimmed.ends_with(']') || trimmed.len() < 2 {
+        return Err(ParseMatrixError::UnbalancedBrackets);
+    }
+    let inner = &trimmed[1..trimmed.len() - 1];
+    let offset = s.len() - s.trim_start().len() + 1;
+    if inner.trim_start().starts_with('[') {
+        parse_numpy(inner, offset)
+    } else if inner.contains(['[', ']']) {
+        Err(ParseMatrixError::UnbalancedBrackets)
+    } else {
+        parse_matlab(inner)
+    }
+}
+
+/// Parses a matrix and reports malformed input instead of falling back to default values.
+///
+/// Three formats are accepted:
+/// * `1 2;3 4` like `From<String>`, every `;` separated group is a **column**
+/// * `[1 2; 3 4]` MATLAB style, every `;` separated group is a **row**
+/// * `[[1, 2], [3, 4]]` NumPy style, every inner list is a **row**
+///
+/// entries can be separated by whitespace and/or commas.
+impl<T: FromStr + Default + Copy, const M: usize, const N: usize> FromStr
+    for Matrix<T, { M }, { N }>
+{
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_columns::<T>(s)?;
+        let found = (parsed.rows, parsed.columns.len());
+        if found != (M, N) && M * N + found.0 * found.1 != 0 {
+            return Err(ParseMatrixError::DimensionMismatch {
+                expected: (M, N),
+                found,
+            });
+        }
+
+        let mut mat = Matrix::default();
+        for (n, column) in parsed.columns.into_iter().enumerate() {
+            for (m, value) in column.into_iter().enumerate() {
+                mat[[m, n]] = value;
+            }
+        }
+        Ok(mat)
+    }
+}
+
+/// accepts the same formats as `Matrix`
+impl<T: FromStr> FromStr for DMatrix<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_columns::<T>(s)?;
+        let size = (parsed.rows, parsed.columns.len());
+        Ok(DMatrix::from_vec(
+            parsed.columns.into_iter().flatten().collect(),
+            size,
+        ))
+    }
+}
+
+/// accepts the same formats as `Matrix`, as long as the result is a single row or column
+impl<T: FromStr> FromStr for DVector<T> {
+    type Err = ParseMatrixError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_columns::<T>(s)?;
+        let (rows, columns) = (parsed.rows, parsed.columns.len());
+        if columns > 1 && rows > 1 {
+            return Err(ParseMatrixError::NotAVector { rows, columns });
+        }
+        Ok(DVector::new(parsed.columns.into_iter().flatten().collect()))
+    }
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use crate::algebra::linear::{DMatrix, DVector, Matrix, ParseMatrixError, Vector};
+
+    #[test]
+    fn formats() {
+        let expected = Matrix::<i32, 2, 3>::new([[2, 3], [-1, 4], [0, -2]]);
+        assert_eq!("2 3;-1 4;0 -2".parse(), Ok(expected));
+        assert_eq!("[2 -1 0; 3 4 -2]".parse(), Ok(expected));
+        assert_eq!("[2, -1, 0; 3, 4, -2]".parse(), Ok(expected));
+        assert_eq!("[[2, -1, 0], [3, 4, -2]]".parse(), Ok(expected));
+        assert_eq!(" [ [2 -1 0] [3 4 -2] ] ".parse(), Ok(expected));
+
+        assert_eq!("2 3 -5".parse(), Ok(Vector::from([2, 3, -5])));
+        assert_eq!("[2; 3; -5]".parse(), Ok(Vector::from([2, 3, -5])));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            "[1 2; 3 x]".parse::<Matrix<i32, 2, 2>>(),
+            Err(ParseMatrixError::InvalidToken {
+                row: 1,
+                column: 1,
+                token: "x".to_string()
+            })
+        );
+        assert_eq!(
+            "1 2;3".parse::<Matrix<i32, 2, 2>>(),
+            Err(ParseMatrixError::RaggedColumn {
+                column: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            "[[1, 2], [3, 4, 5]]".parse::<DMatrix<i32>>().unwrap_err(),
+            ParseMatrixError::RaggedRow {
+                row: 1,
+                expected: 2,
+                found: 3
+            }
+        );
+        assert_eq!(
+            "[1 2 3; 4 5 6]".parse::<Matrix<i32, 2, 2>>(),
+            Err(ParseMatrixError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 3)
+            })
+        );
+        assert_eq!(
+            "[1, , 2]".parse::<DVector<i32>>().unwrap_err(),
+            ParseMatrixError::InvalidToken {
+                row: 0,
+                column: 1,
+                token: "".to_string()
+            }
+        );
+        assert_eq!(
+            "[[1, 2], [3, 4]".parse::<DMatrix<i32>>().unwrap_err(),
+            ParseMatrixError::UnbalancedBrackets
+        );
+        assert_eq!(
+            "[[1, 2] x [3, 4]]".parse::<DMatrix<i32>>().unwrap_err(),
+            ParseMatrixError::UnexpectedCharacter {
+                position: 8,
+                character: 'x'
+            }
+        );
+    }
+
+    #[test]
+    fn dynamic() {
+        let mat = "[1.5 2; 3 4; 5 6]".parse::<DMatrix<f64>>().unwrap();
+        assert_eq!(mat.size(), (3, 2));
+        assert_eq!(mat.as_slice(), [1.5, 3.0, 5.0, 2.0, 4.0, 6.0]);
+
+        let vec = "[1, 2, 3]".parse::<DVector<i32>>().unwrap();
+        assert_eq!((vec.data, vec.len), (vec![1, 2, 3], 3));
+        let vec = "4 3 2".parse::<DVector<i32>>().unwrap();
+        assert_eq!(vec.data, vec![4, 3, 2]);
+        assert_eq!(
+            "[1 2; 3 4]".parse::<DVector<i32>>().unwrap_err(),
+            ParseMatrixError::NotAVector {
+                rows: 2,
+                columns: 2
+            }
+        );
+    }
+}
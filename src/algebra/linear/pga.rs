@@ -0,0 +1,915 @@
+//! 3D projective geometric algebra, the clifford algebra `Cl(3, 0, 1)`
+//!
+//! `e1`, `e2` and `e3` square to `1` and the projective `e0` squares to `0`.
+//! planes are vectors, lines are bivectors and points are trivectors,
+//! the even elements are motors which rotate and translate all of them.
+//!
+//! the products are written as operators like `Multivector` does,
+//! `^` is the outer product (meet), `&` the regressive product (join) and `|` the inner product.
+//! points and planes are homogeneous, scaling them doesn't change where they are.
+//!
+//! unlike the other modules of `linear` this one is public and not glob re-exported,
+//! `Point`, `Line`, `Plane` and `Motor` are too generic to sit next to `Vector` and `Matrix`.
+use crate::algebra::linear::bivec::Bivector3;
+use crate::algebra::linear::mat::SquareMatrix;
+use crate::algebra::linear::multivector::Multivector;
+use crate::algebra::linear::quaternion::Quaternion;
+use crate::algebra::linear::rotor::Rotor3;
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::vec::Vector;
+use fructose::operators::{ClosedNeg, ClosedOps};
+use fructose::properties::helpers::float::Float;
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::specific::complex::Real;
+use std::ops::{BitAnd, BitOr, BitXor, Mul, MulAssign, Neg};
+
+/// the plane `e1 x + e2 y + e3 z + e0 = 0`
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Plane<T> {
+    pub e0: T,
+    pub e1: T,
+    pub e2: T,
+    pub e3: T,
+}
+
+/// line in plücker coordinates
+///
+/// `(e23, e31, e12)` is the direction and `(e01, e02, e03)` the moment,
+/// the cross product of any point on the line with the direction.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Line<T> {
+    pub e01: T,
+    pub e02: T,
+    pub e03: T,
+    pub e12: T,
+    pub e31: T,
+    pub e23: T,
+}
+
+/// the point `(e032, e013, e021) / e123`, points with `e123 = 0` are directions
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Point<T> {
+    pub e032: T,
+    pub e013: T,
+    pub e021: T,
+    pub e123: T,
+}
+
+/// rigid transform, applied to `X` with the sandwich product `M X M~`
+///
+/// the euclidean part `s + e23 + e31 + e12` is the rotation and the remaining part the translation.
+/// `lhs * rhs` is the motor that applies `rhs` first.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Motor<T> {
+    pub s: T,
+    pub e23: T,
+    pub e31: T,
+    pub e12: T,
+    pub e01: T,
+    pub e02: T,
+    pub e03: T,
+    pub e0123: T,
+}
+
+impl<T> Plane<T> {
+    /// the plane `ax + by + cz + d = 0`
+    #[inline]
+    pub const fn new(a: T, b: T, c: T, d: T) -> Self {
+        Self {
+            e0: d,
+            e1: a,
+            e2: b,
+            e3: c,
+        }
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps> Plane<T> {
+    #[inline]
+    pub fn normal(&self) -> Vector<T, 3> {
+        Vector::from([self.e1, self.e2, self.e3])
+    }
+
+    /// the length of the normal
+    #[inline]
+    pub fn magnitude(&self) -> T {
+        self.normal().magnitude()
+    }
+
+    /// scales the normal to unit length, `e0` is then the signed distance of the origin
+    #[inline]
+    pub fn normalize(&mut self) {
+        let scale = T::one() / self.magnitude();
+        self.e0 *= scale;
+        self.e1 *= scale;
+        self.e2 *= scale;
+        self.e3 *= scale;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut plane = *self;
+        plane.normalize();
+        plane
+    }
+}
+
+impl<T: Scalar + ClosedOps> Line<T> {
+    #[inline]
+    pub fn new(direction: Vector<T, 3>, moment: Vector<T, 3>) -> Self {
+        Self {
+            e01: moment[0],
+            e02: moment[1],
+            e03: moment[2],
+            e12: direction[2],
+            e31: direction[1],
+            e23: direction[0],
+        }
+    }
+
+    /// the line through `point` along `direction`
+    #[inline]
+    pub fn from_point_direction(point: Vector<T, 3>, direction: Vector<T, 3>) -> Self {
+        Self::new(direction, point.cross(direction))
+    }
+
+    #[inline]
+    pub fn direction(&self) -> Vector<T, 3> {
+        Vector::from([self.e23, self.e31, self.e12])
+    }
+
+    #[inline]
+    pub fn moment(&self) -> Vector<T, 3> {
+        Vector::from([self.e01, self.e02, self.e03])
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> Line<T> {
+    #[inline]
+    pub fn magnitude(&self) -> T {
+        self.direction().magnitude()
+    }
+
+    /// scales the direction to unit length
+    #[inline]
+    pub fn normalize(&mut self) {
+        *self = *self * (T::one() / self.magnitude());
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut line = *self;
+        line.normalize();
+        line
+    }
+
+    /// the motor `exp(self)`
+    ///
+    /// `exp(-angle / 2 * line)` rotates by `angle` around the normalized `line`,
+    /// `exp(-1 / 2 * line)` translates by the moment of a line without direction.
+    pub fn exp(&self) -> Motor<T> {
+        let l = self.e23 * self.e23 + self.e31 * self.e31 + self.e12 * self.e12;
+        let a = l.sqrt();
+        if a <= <T as Float>::EPSILON {
+            return Motor {
+                s: T::one(),
+                e23: T::zero(),
+                e31: T::zero(),
+                e12: T::zero(),
+                e01: self.e01,
+                e02: self.e02,
+                e03: self.e03,
+                e0123: T::zero(),
+            };
+        }
+
+        // the square is -l + 2m e0123, which makes the exponential a dual number cos and sin
+        let m = self.e23 * self.e01 + self.e31 * self.e02 + self.e12 * self.e03;
+        let (sin, c) = a.sin_cos();
+        let s = sin / a;
+        let t = m / l * (c - s);
+        Motor {
+            s: c,
+            e23: s * self.e23,
+            e31: s * self.e31,
+            e12: s * self.e12,
+            e01: s * self.e01 + t * self.e23,
+            e02: s * self.e02 + t * self.e31,
+            e03: s * self.e03 + t * self.e12,
+            e0123: m * s,
+        }
+    }
+}
+
+impl<T: Scalar + One + Zero> Point<T> {
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self {
+            e032: x,
+            e013: y,
+            e021: z,
+            e123: T::one(),
+        }
+    }
+
+    /// the point at infinity in `direction`
+    #[inline]
+    pub fn direction(direction: Vector<T, 3>) -> Self {
+        Self {
+            e032: direction[0],
+            e013: direction[1],
+            e021: direction[2],
+            e123: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps> Point<T> {
+    /// the euclidean position, only meaningful for finite points
+    #[inline]
+    pub fn into_vector(self) -> Vector<T, 3> {
+        Vector::from([
+            self.e032 / self.e123,
+            self.e013 / self.e123,
+            self.e021 / self.e123,
+        ])
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> Point<T> {
+    /// the closest point on `plane`
+    #[inline]
+    pub fn project_onto_plane(&self, plane: &Plane<T>) -> Self {
+        (*plane | *self) ^ *plane
+    }
+
+    /// the closest point on `line`
+    #[inline]
+    pub fn project_onto_line(&self, line: &Line<T>) -> Self {
+        -((*line | *self) ^ *line)
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> Line<T> {
+    /// the orthogonal projection of the line onto `plane`
+    #[inline]
+    pub fn project_onto_plane(&self, plane: &Plane<T>) -> Self {
+        *plane ^ (*plane | *self)
+    }
+}
+
+impl<T: Scalar + One + Zero> From<Vector<T, 3>> for Point<T> {
+    fn from(rhs: Vector<T, 3>) -> Self {
+        Self::new(rhs[0], rhs[1], rhs[2])
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Motor<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            s: T::one(),
+            ..Self::default()
+        }
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        Self {
+            s: T::one(),
+            e01: -translation[0] * half,
+            e02: -translation[1] * half,
+            e03: -translation[2] * half,
+            ..Self::default()
+        }
+    }
+
+    /// the rotation part as a quaternion
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<T> {
+        Quaternion::new(-self.e23, -self.e31, -self.e12, self.s)
+    }
+
+    /// the translation of a normalized motor, applied after the rotation
+    #[inline]
+    pub fn translation(&self) -> Vector<T, 3> {
+        let two = T::one() + T::one();
+        Vector::from([
+            self.e01 * self.s + self.e02 * self.e12 - self.e03 * self.e31 + self.e0123 * self.e23,
+            self.e02 * self.s - self.e01 * self.e12 + self.e03 * self.e23 + self.e0123 * self.e31,
+            self.e03 * self.s + self.e01 * self.e31 - self.e02 * self.e23 + self.e0123 * self.e12,
+        ]) * -two
+    }
+
+    /// reverses the order of the basis vectors, this is the inverse of a normalized motor
+    #[inline]
+    pub fn reverse(&mut self) {
+        *self = self.reversed();
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        Self {
+            s: self.s,
+            e23: -self.e23,
+            e31: -self.e31,
+            e12: -self.e12,
+            e01: -self.e01,
+            e02: -self.e02,
+            e03: -self.e03,
+            e0123: self.e0123,
+        }
+    }
+
+    /// the homogeneous transform of a normalized motor
+    #[inline]
+    pub fn into_matrix(self) -> SquareMatrix<T, 4> {
+        let mut mat = self.rotation().into_matrix4();
+        let translation = self.translation();
+        for i in 0..3 {
+            mat[[i, 3]] = translation[i];
+        }
+        mat
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg + Float> Motor<T> {
+    /// rotates by `angle` around `line`, counterclockwise when looking against its direction
+    #[inline]
+    pub fn from_rotation(angle: T, line: &Line<T>) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        (line.normalized() * (-angle * half)).exp()
+    }
+
+    /// scales the motor so `self * self~ = 1`
+    #[inline]
+    pub fn normalize(&mut self) {
+        let a = self.s * self.s + self.e23 * self.e23 + self.e31 * self.e31 + self.e12 * self.e12;
+        let b =
+            self.s * self.e0123 - (self.e23 * self.e01 + self.e31 * self.e02 + self.e12 * self.e03);
+        // 1 / sqrt(a + 2b e0123) = scale - scale * b / a e0123
+        let scale = T::one() / a.sqrt();
+        let dual = -b * scale / a;
+        *self = Self {
+            s: self.s * scale,
+            e23: self.e23 * scale,
+            e31: self.e31 * scale,
+            e12: self.e12 * scale,
+            e01: self.e01 * scale - dual * self.e23,
+            e02: self.e02 * scale - dual * self.e31,
+            e03: self.e03 * scale - dual * self.e12,
+            e0123: self.e0123 * scale + dual * self.s,
+        };
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut motor = *self;
+        motor.normalize();
+        motor
+    }
+
+    /// the bivector `B` of a normalized motor with `exp(B) = self`
+    pub fn log(&self) -> Line<T> {
+        let sin = (self.e23 * self.e23 + self.e31 * self.e31 + self.e12 * self.e12).sqrt();
+        if sin <= <T as Float>::EPSILON {
+            return Line {
+                e01: self.e01 / self.s,
+                e02: self.e02 / self.s,
+                e03: self.e03 / self.s,
+                ..Line::default()
+            };
+        }
+
+        let a = sin.atan2(self.s);
+        let s = sin / a;
+        let m = self.e0123 / s;
+        let t = m / (a * a) * (self.s - s);
+        let (e23, e31, e12) = (self.e23 / s, self.e31 / s, self.e12 / s);
+        Line {
+            e01: (self.e01 - t * e23) / s,
+            e02: (self.e02 - t * e31) / s,
+            e03: (self.e03 - t * e12) / s,
+            e12,
+            e31,
+            e23,
+        }
+    }
+
+    /// screw linear interpolation, moves along the shortest screw motion from `self` to `other`
+    #[inline]
+    pub fn interpolate(&self, other: &Self, t: T) -> Self {
+        let mut delta = *other * self.reversed();
+        if delta.s < T::zero() {
+            delta = -delta;
+        }
+        (delta.log() * t).exp() * *self
+    }
+}
+
+impl<T: Scalar + ClosedNeg> From<Rotor3<T>> for Motor<T> {
+    fn from(rhs: Rotor3<T>) -> Self {
+        Self {
+            s: rhs.scalar,
+            e23: rhs.bivector.yz,
+            e31: -rhs.bivector.xz,
+            e12: rhs.bivector.xy,
+            ..Self::default()
+        }
+    }
+}
+
+impl<T: Scalar + ClosedNeg> From<Motor<T>> for Rotor3<T> {
+    /// the rotation part, the translation is dropped
+    fn from(rhs: Motor<T>) -> Self {
+        Rotor3::new(rhs.s, Bivector3::new(rhs.e12, -rhs.e31, rhs.e23))
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<Motor<T>> for SquareMatrix<T, 4> {
+    fn from(rhs: Motor<T>) -> Self {
+        rhs.into_matrix()
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul for Motor<T> {
+    type Output = Self;
+
+    fn mul(self, b: Self) -> Self::Output {
+        let a = self;
+        Self {
+            s: a.s * b.s - a.e23 * b.e23 - a.e31 * b.e31 - a.e12 * b.e12,
+            e23: a.s * b.e23 + a.e23 * b.s - a.e31 * b.e12 + a.e12 * b.e31,
+            e31: a.s * b.e31 + a.e23 * b.e12 + a.e31 * b.s - a.e12 * b.e23,
+            e12: a.s * b.e12 - a.e23 * b.e31 + a.e31 * b.e23 + a.e12 * b.s,
+            e01: a.s * b.e01 - a.e23 * b.e0123 - a.e31 * b.e03 + a.e12 * b.e02 + a.e01 * b.s
+                - a.e02 * b.e12
+                + a.e03 * b.e31
+                - a.e0123 * b.e23,
+            e02: a.s * b.e02 + a.e23 * b.e03 - a.e31 * b.e0123 - a.e12 * b.e01
+                + a.e01 * b.e12
+                + a.e02 * b.s
+                - a.e03 * b.e23
+                - a.e0123 * b.e31,
+            e03: a.s * b.e03 - a.e23 * b.e02 + a.e31 * b.e01 - a.e12 * b.e0123 - a.e01 * b.e31
+                + a.e02 * b.e23
+                + a.e03 * b.s
+                - a.e0123 * b.e12,
+            e0123: a.s * b.e0123
+                + a.e23 * b.e01
+                + a.e31 * b.e02
+                + a.e12 * b.e03
+                + a.e01 * b.e23
+                + a.e02 * b.e31
+                + a.e03 * b.e12
+                + a.e0123 * b.s,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps> MulAssign for Motor<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Mul<Point<T>> for Motor<T> {
+    type Output = Point<T>;
+
+    /// moves the point, `self` has to be normalized
+    fn mul(self, rhs: Point<T>) -> Self::Output {
+        let position = self.rotation() * Vector::from([rhs.e032, rhs.e013, rhs.e021])
+            + self.translation() * rhs.e123;
+        Point {
+            e032: position[0],
+            e013: position[1],
+            e021: position[2],
+            e123: rhs.e123,
+        }
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Mul<Line<T>> for Motor<T> {
+    type Output = Line<T>;
+
+    /// moves the line, `self` has to be normalized
+    fn mul(self, rhs: Line<T>) -> Self::Output {
+        let rotation = self.rotation();
+        let direction = rotation * rhs.direction();
+        let moment = rotation * rhs.moment() + self.translation().cross(direction);
+        Line::new(direction, moment)
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Mul<Plane<T>> for Motor<T> {
+    type Output = Plane<T>;
+
+    /// moves the plane, `self` has to be normalized
+    fn mul(self, rhs: Plane<T>) -> Self::Output {
+        let normal = self.rotation() * Vector::from([rhs.e1, rhs.e2, rhs.e3]);
+        Plane {
+            e0: rhs.e0 - normal.dot(self.translation()),
+            e1: normal[0],
+            e2: normal[1],
+            e3: normal[2],
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul<T> for Line<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            e01: self.e01 * rhs,
+            e02: self.e02 * rhs,
+            e03: self.e03 * rhs,
+            e12: self.e12 * rhs,
+            e31: self.e31 * rhs,
+            e23: self.e23 * rhs,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for Motor<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            s: -self.s,
+            e23: -self.e23,
+            e31: -self.e31,
+            e12: -self.e12,
+            e01: -self.e01,
+            e02: -self.e02,
+            e03: -self.e03,
+            e0123: -self.e0123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            e032: -self.e032,
+            e013: -self.e013,
+            e021: -self.e021,
+            e123: -self.e123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps> BitXor for Plane<T> {
+    type Output = Line<T>;
+
+    /// meet, the line where both planes intersect
+    fn bitxor(self, b: Self) -> Self::Output {
+        let a = self;
+        Line {
+            e01: a.e0 * b.e1 - a.e1 * b.e0,
+            e02: a.e0 * b.e2 - a.e2 * b.e0,
+            e03: a.e0 * b.e3 - a.e3 * b.e0,
+            e12: a.e1 * b.e2 - a.e2 * b.e1,
+            e31: a.e3 * b.e1 - a.e1 * b.e3,
+            e23: a.e2 * b.e3 - a.e3 * b.e2,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitXor<Plane<T>> for Line<T> {
+    type Output = Point<T>;
+
+    /// meet, the point where the line crosses the plane
+    fn bitxor(self, p: Plane<T>) -> Self::Output {
+        let l = self;
+        Point {
+            e032: l.e03 * p.e2 - l.e02 * p.e3 - l.e23 * p.e0,
+            e013: l.e01 * p.e3 - l.e03 * p.e1 - l.e31 * p.e0,
+            e021: l.e02 * p.e1 - l.e01 * p.e2 - l.e12 * p.e0,
+            e123: l.e12 * p.e3 + l.e31 * p.e2 + l.e23 * p.e1,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitXor<Line<T>> for Plane<T> {
+    type Output = Point<T>;
+
+    /// meet, the point where the line crosses the plane
+    fn bitxor(self, rhs: Line<T>) -> Self::Output {
+        rhs ^ self
+    }
+}
+
+impl<T: Scalar + ClosedOps> BitAnd for Point<T> {
+    type Output = Line<T>;
+
+    /// join, the line from `self` to `rhs`
+    fn bitand(self, b: Self) -> Self::Output {
+        let a = self;
+        Line {
+            e01: a.e013 * b.e021 - a.e021 * b.e013,
+            e02: a.e021 * b.e032 - a.e032 * b.e021,
+            e03: a.e032 * b.e013 - a.e013 * b.e032,
+            e12: a.e123 * b.e021 - a.e021 * b.e123,
+            e31: a.e123 * b.e013 - a.e013 * b.e123,
+            e23: a.e123 * b.e032 - a.e032 * b.e123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitAnd<Point<T>> for Line<T> {
+    type Output = Plane<T>;
+
+    /// join, the plane containing the line and the point
+    fn bitand(self, p: Point<T>) -> Self::Output {
+        let l = self;
+        Plane {
+            e0: l.e01 * p.e032 + l.e02 * p.e013 + l.e03 * p.e021,
+            e1: l.e12 * p.e013 - l.e31 * p.e021 - l.e01 * p.e123,
+            e2: l.e23 * p.e021 - l.e12 * p.e032 - l.e02 * p.e123,
+            e3: l.e31 * p.e032 - l.e23 * p.e013 - l.e03 * p.e123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitAnd<Line<T>> for Point<T> {
+    type Output = Plane<T>;
+
+    /// join, the plane containing the line and the point
+    fn bitand(self, rhs: Line<T>) -> Self::Output {
+        rhs & self
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitOr<Point<T>> for Plane<T> {
+    type Output = Line<T>;
+
+    /// the line through the point orthogonal to the plane
+    fn bitor(self, q: Point<T>) -> Self::Output {
+        let p = self;
+        Line {
+            e01: p.e3 * q.e013 - p.e2 * q.e021,
+            e02: p.e1 * q.e021 - p.e3 * q.e032,
+            e03: p.e2 * q.e032 - p.e1 * q.e013,
+            e12: p.e3 * q.e123,
+            e31: p.e2 * q.e123,
+            e23: p.e1 * q.e123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitOr<Point<T>> for Line<T> {
+    type Output = Plane<T>;
+
+    /// the plane through the point orthogonal to the line
+    fn bitor(self, q: Point<T>) -> Self::Output {
+        let l = self;
+        Plane {
+            e0: l.e12 * q.e021 + l.e31 * q.e013 + l.e23 * q.e032,
+            e1: -l.e23 * q.e123,
+            e2: -l.e31 * q.e123,
+            e3: -l.e12 * q.e123,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> BitOr<Line<T>> for Plane<T> {
+    type Output = Plane<T>;
+
+    /// the plane through the line orthogonal to `self`
+    fn bitor(self, l: Line<T>) -> Self::Output {
+        let p = self;
+        Plane {
+            e0: -(p.e1 * l.e01 + p.e2 * l.e02 + p.e3 * l.e03),
+            e1: p.e3 * l.e31 - p.e2 * l.e12,
+            e2: p.e1 * l.e12 - p.e3 * l.e23,
+            e3: p.e2 * l.e23 - p.e1 * l.e31,
+        }
+    }
+}
+
+/// conversions into the general multivector, `Multivector` orders the basis as `e1, e2, e3, e0`
+macro_rules! impl_multivector_conversions {
+    ($ty:ident { $($field:ident: $blade:literal $negate:literal),* $(,)? }) => {
+        impl<T: Scalar + ClosedNeg> From<$ty<T>> for Multivector<T, 3, 0, 1> {
+            fn from(rhs: $ty<T>) -> Self {
+                let mut mv = Self::zero();
+                $(mv[$blade] = if $negate { -rhs.$field } else { rhs.$field };)*
+                mv
+            }
+        }
+
+        impl<T: Scalar + ClosedNeg> From<Multivector<T, 3, 0, 1>> for $ty<T> {
+            /// the components of the matching grade, everything else is dropped
+            fn from(rhs: Multivector<T, 3, 0, 1>) -> Self {
+                Self {
+                    $($field: if $negate { -rhs[$blade] } else { rhs[$blade] },)*
+                }
+            }
+        }
+    };
+}
+
+impl_multivector_conversions!(Plane {
+    e0: 0b1000 false,
+    e1: 0b0001 false,
+    e2: 0b0010 false,
+    e3: 0b0100 false,
+});
+
+impl_multivector_conversions!(Line {
+    e01: 0b1001 true,
+    e02: 0b1010 true,
+    e03: 0b1100 true,
+    e12: 0b0011 false,
+    e31: 0b0101 true,
+    e23: 0b0110 false,
+});
+
+impl_multivector_conversions!(Point {
+    e032: 0b1110 true,
+    e013: 0b1101 false,
+    e021: 0b1011 true,
+    e123: 0b0111 false,
+});
+
+impl_multivector_conversions!(Motor {
+    s: 0b0000 false,
+    e23: 0b0110 false,
+    e31: 0b0101 true,
+    e12: 0b0011 false,
+    e01: 0b1001 true,
+    e02: 0b1010 true,
+    e03: 0b1100 true,
+    e0123: 0b1111 true,
+});
+
+#[cfg(test)]
+mod pga_tests {
+    use crate::algebra::linear::pga::{Line, Motor, Plane, Point};
+    use crate::algebra::linear::{Bivector3, Multivector, Rotor3, Vector};
+    use crate::assert_approx_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    type Pga = Multivector<f64, 3, 0, 1>;
+
+    fn motor() -> Motor<f64> {
+        let axis = Line::from_point_direction(
+            Vector::from([1.0, -2.0, 0.5]),
+            Vector::from([0.3, 0.4, -1.2]),
+        );
+        Motor::from_translation(Vector::from([0.5, 2.0, -1.0])) * Motor::from_rotation(0.9, &axis)
+    }
+
+    #[test]
+    fn join_and_meet() {
+        let a = Point::new(1.0, 2.0, 3.0);
+        let b = Point::new(2.0, 2.0, 5.0);
+        let line = a & b;
+        assert_eq!(line.direction(), Vector::from([1.0, 0.0, 2.0]));
+        assert_eq!(
+            line.moment(),
+            Vector::from([1.0, 2.0, 3.0]).cross(line.direction())
+        );
+
+        let plane = line & Point::new(0.0, 0.0, 0.0);
+        for point in [a, b] {
+            let p = point.into_vector();
+            assert_eq!(plane.normal().dot(p) + plane.e0, 0.0);
+        }
+
+        // x = 1 and z = 3 meet in a line parallel to y, which crosses y = 4 at (1, 4, 3)
+        let x = Plane::new(1.0, 0.0, 0.0, -1.0);
+        let z = Plane::new(0.0, 0.0, 1.0, -3.0);
+        let y = Plane::new(0.0, 2.0, 0.0, -8.0);
+        let line = x ^ z;
+        assert_eq!(line.direction(), Vector::from([0.0, -1.0, 0.0]));
+        assert_eq!((line ^ y).into_vector(), Vector::from([1.0, 4.0, 3.0]));
+        assert_eq!((x ^ z ^ y).into_vector(), Vector::from([1.0, 4.0, 3.0]));
+
+        // the products agree with the general multivector,
+        // its pseudoscalar e1 e2 e3 e0 = -e0123 flips the sign of the regressive product
+        let (pa, pb) = (Pga::from(a), Pga::from(b));
+        assert_eq!(Line::from(-(pa.clone() & pb)), a & b);
+        assert_eq!(Plane::from(-(Pga::from(line) & pa.clone())), line & a);
+        assert_eq!(Point::from(Pga::from(line) ^ Pga::from(y)), line ^ y);
+        assert_eq!(Line::from(Pga::from(y) | pa.clone()), y | a);
+        assert_eq!(Plane::from(Pga::from(line) | pa), line | a);
+        assert_eq!(Plane::from(Pga::from(y) | Pga::from(line)), y | line);
+    }
+
+    #[test]
+    fn projection() {
+        let plane = Plane::new(0.0, 0.0, 2.0, -2.0);
+        let point = Point::new(3.0, -1.0, 5.0);
+        assert_approx_eq!(
+            point.project_onto_plane(&plane).into_vector(),
+            Vector::from([3.0, -1.0, 1.0])
+        );
+
+        let line = Line::from_point_direction(
+            Vector::from([1.0, 1.0, 0.0]),
+            Vector::from([0.0, 0.0, 3.0]),
+        );
+        let projected = point.project_onto_line(&line);
+        assert!(projected.e123 > 0.0);
+        assert_approx_eq!(projected.into_vector(), Vector::from([1.0, 1.0, 5.0]));
+
+        let line = Point::new(0.0, 0.0, 4.0) & Point::new(1.0, 1.0, 6.0);
+        let projected = line.project_onto_plane(&plane);
+        assert_approx_eq!(
+            projected.normalized(),
+            (Point::new(0.0, 0.0, 1.0) & Point::new(1.0, 1.0, 1.0)).normalized()
+        );
+    }
+
+    #[test]
+    fn motors() {
+        let motor = motor();
+        let reversed = Pga::from(motor.reversed());
+
+        let point = Point::new(1.0, 2.0, 3.0);
+        let expected = Pga::from(motor)
+            .geometric(&Pga::from(point))
+            .geometric(&reversed);
+        assert_approx_eq!(motor * point, Point::from(expected), epsilon = 1e-12);
+
+        let line = Point::new(-1.0, 0.5, 2.0) & point;
+        let expected = Pga::from(motor).sandwich(&Pga::from(line));
+        assert_approx_eq!(motor * line, Line::from(expected), epsilon = 1e-12);
+
+        let plane = Plane::new(1.0, -2.0, 0.5, 3.0);
+        let expected = Pga::from(motor).sandwich(&Pga::from(plane));
+        assert_approx_eq!(motor * plane, Plane::from(expected), epsilon = 1e-12);
+
+        let composed = Motor::from(Pga::from(motor) * Pga::from(motor));
+        assert_approx_eq!(motor * motor, composed, epsilon = 1e-12);
+
+        let mat = motor.into_matrix();
+        let moved = mat * Vector::from([1.0, 2.0, 3.0, 1.0]);
+        assert_approx_eq!(
+            (motor * point).into_vector(),
+            Vector::from([moved[0], moved[1], moved[2]]),
+            epsilon = 1e-12
+        );
+
+        let rotor = Rotor3::from_angle_plane(0.4, Bivector3::new(0.0, 0.6, 0.8));
+        let vec = Vector::from([1.0, -1.0, 2.0]);
+        assert_approx_eq!(
+            (Motor::from(rotor) * Point::from(vec)).into_vector(),
+            rotor * vec,
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(Rotor3::from(Motor::from(rotor)), rotor);
+
+        let mut scaled =
+            Motor::from(Pga::from(motor) * (Pga::scalar(2.0) + Pga::blade(0b1111, 0.3)));
+        scaled.normalize();
+        assert_approx_eq!(scaled, motor, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn small_rotation() {
+        // the squared magnitude of such a rotation is below `EPSILON`, it must not be dropped
+        let line = Line {
+            e01: 0.3,
+            e02: -0.2,
+            e03: 0.5,
+            e23: 1e-9,
+            ..Line::default()
+        };
+        let motor = line.exp();
+        assert_approx_eq!(motor.e23, 1e-9);
+        assert_approx_eq!(motor.e0123, 3e-10);
+        assert_approx_eq!(motor.log(), line);
+    }
+
+    #[test]
+    fn interpolation() {
+        let motor = motor();
+        assert_approx_eq!(motor.log().exp(), motor, epsilon = 1e-12);
+        let translation = Motor::from_translation(Vector::from([1.0, 2.0, 3.0]));
+        assert_approx_eq!(translation.log().exp(), translation);
+
+        // a quarter turn around z combined with a lift along z is a screw motion
+        let z = Line::new(Vector::from([0.0, 0.0, 1.0]), Vector::from([0.0, 0.0, 0.0]));
+        let target = Motor::from_translation(Vector::from([0.0, 0.0, 2.0]))
+            * Motor::from_rotation(FRAC_PI_2, &z);
+        let halfway = Motor::identity().interpolate(&target, 0.5);
+        assert_approx_eq!(
+            (halfway * Point::new(1.0, 0.0, 0.0)).into_vector(),
+            Vector::from([0.5f64.sqrt(), 0.5f64.sqrt(), 1.0]),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(motor.interpolate(&target, 0.0), motor, epsilon = 1e-12);
+        assert_approx_eq!(motor.interpolate(&target, 1.0), target, epsilon = 1e-12);
+        assert_approx_eq!(motor.interpolate(&-target, 1.0), target, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,518 @@
+use crate::algebra::linear::bivec::Bivector3;
+use crate::algebra::linear::mat::SquareMatrix;
+use crate::algebra::linear::rotor::Rotor3;
+use crate::algebra::linear::scalar::Scalar;
+use crate::algebra::linear::vec::Vector;
+use fructose::operators::{Additive, ClosedAdd, ClosedNeg, ClosedOps, ClosedSub, Multiplicative};
+use fructose::properties::general::{Associative, Identity, Invertible, Set, Total};
+use fructose::properties::helpers::identity::{One, Zero};
+use fructose::specific::complex::Real;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// quaternion `w + xi + yj + zk`
+///
+/// stored as `[x, y, z, w]` like most file formats and graphics apis do.
+/// unit quaternions rotate vectors with `q * v * q^*`,
+/// `lhs * rhs` is the rotation that applies `rhs` first and `lhs` second.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Quaternion<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T> Quaternion<T> {
+    #[inline]
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    pub fn layout() -> std::alloc::Layout {
+        std::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<T>())
+            .unwrap()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const T, 4) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T, 4) }
+    }
+
+    #[inline]
+    pub const fn as_ptr(&self) -> *const T {
+        self as *const Self as *const T
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self as *mut Self as *mut T
+    }
+}
+
+impl<T: Scalar> Quaternion<T> {
+    #[inline]
+    pub fn from_scalar_vector(scalar: T, vector: Vector<T, 3>) -> Self {
+        Self::new(vector[0], vector[1], vector[2], scalar)
+    }
+
+    #[inline]
+    pub fn scalar(&self) -> T {
+        self.w
+    }
+
+    #[inline]
+    pub fn vector(&self) -> Vector<T, 3> {
+        Vector::from([self.x, self.y, self.z])
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Quaternion<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::one())
+    }
+
+    #[inline]
+    pub fn conjugate(&mut self) {
+        self.x = -self.x;
+        self.y = -self.y;
+        self.z = -self.z;
+    }
+
+    #[inline]
+    pub fn conjugated(&self) -> Self {
+        let mut q = *self;
+        q.conjugate();
+        q
+    }
+
+    #[inline]
+    pub fn dot(&self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+    }
+
+    /// rotates `vec` by this quaternion, which has to be normalized
+    #[inline]
+    pub fn rotate_vec(self, vec: &mut Vector<T, 3>) {
+        // v + 2w(u x v) + 2u x (u x v)
+        let two = T::one() + T::one();
+        let u = self.vector();
+        let uv = u.cross(*vec);
+        let uuv = u.cross(uv);
+        for i in 0..3 {
+            vec[i] += two * (self.w * uv[i] + uuv[i]);
+        }
+    }
+
+    /// the rotation matrix of a normalized quaternion
+    #[inline]
+    pub fn into_matrix(self) -> SquareMatrix<T, 3> {
+        let two = T::one() + T::one();
+        let Self { x, y, z, w } = self;
+
+        let mut mat = SquareMatrix::<T, 3>::default();
+        mat[[0, 0]] = T::one() - two * (y * y + z * z);
+        mat[[0, 1]] = two * (x * y - w * z);
+        mat[[0, 2]] = two * (x * z + w * y);
+        mat[[1, 0]] = two * (x * y + w * z);
+        mat[[1, 1]] = T::one() - two * (x * x + z * z);
+        mat[[1, 2]] = two * (y * z - w * x);
+        mat[[2, 0]] = two * (x * z - w * y);
+        mat[[2, 1]] = two * (y * z + w * x);
+        mat[[2, 2]] = T::one() - two * (x * x + y * y);
+        mat
+    }
+
+    /// the homogeneous rotation matrix of a normalized quaternion
+    #[inline]
+    pub fn into_matrix4(self) -> SquareMatrix<T, 4> {
+        let rotation = self.into_matrix();
+        let mut mat = SquareMatrix::<T, 4>::default();
+        for j in 0..3 {
+            mat.data[j][..3].copy_from_slice(&rotation.data[j]);
+        }
+        mat[[3, 3]] = T::one();
+        mat
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg> Quaternion<T> {
+    /// counter-clockwise rotation by `angle` radians around `axis`, which does not need to be normalized
+    #[inline]
+    pub fn from_axis_angle(axis: Vector<T, 3>, angle: T) -> Self {
+        let half_angle = angle / (T::one() + T::one());
+        let (sin, cos) = half_angle.sin_cos();
+        let axis = axis.normalized();
+        Self::new(axis[0] * sin, axis[1] * sin, axis[2] * sin, cos)
+    }
+
+    /// rotation from tait-bryan angles in radians, applied in the order roll (x), pitch (y), yaw (z)
+    ///
+    /// this is the `yaw * pitch * roll` convention used in aerospace.
+    #[inline]
+    pub fn from_euler_angles(roll: T, pitch: T, yaw: T) -> Self {
+        let two = T::one() + T::one();
+        let (sr, cr) = (roll / two).sin_cos();
+        let (sp, cp) = (pitch / two).sin_cos();
+        let (sy, cy) = (yaw / two).sin_cos();
+        Self::new(
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        )
+    }
+
+    /// the rotation of an orthonormal matrix with determinant 1
+    pub fn from_matrix(mat: &SquareMatrix<T, 3>) -> Self {
+        let two = T::one() + T::one();
+        let four = two + two;
+        let trace = mat[[0, 0]] + mat[[1, 1]] + mat[[2, 2]];
+
+        // pick the biggest component to divide by for numerical stability
+        if trace > T::zero() {
+            let s = (trace + T::one()).sqrt() * two;
+            Self::new(
+                (mat[[2, 1]] - mat[[1, 2]]) / s,
+                (mat[[0, 2]] - mat[[2, 0]]) / s,
+                (mat[[1, 0]] - mat[[0, 1]]) / s,
+                s / four,
+            )
+        } else if mat[[0, 0]] > mat[[1, 1]] && mat[[0, 0]] > mat[[2, 2]] {
+            let s = (T::one() + mat[[0, 0]] - mat[[1, 1]] - mat[[2, 2]]).sqrt() * two;
+            Self::new(
+                s / four,
+                (mat[[0, 1]] + mat[[1, 0]]) / s,
+                (mat[[0, 2]] + mat[[2, 0]]) / s,
+                (mat[[2, 1]] - mat[[1, 2]]) / s,
+            )
+        } else if mat[[1, 1]] > mat[[2, 2]] {
+            let s = (T::one() + mat[[1, 1]] - mat[[0, 0]] - mat[[2, 2]]).sqrt() * two;
+            Self::new(
+                (mat[[0, 1]] + mat[[1, 0]]) / s,
+                s / four,
+                (mat[[1, 2]] + mat[[2, 1]]) / s,
+                (mat[[0, 2]] - mat[[2, 0]]) / s,
+            )
+        } else {
+            let s = (T::one() + mat[[2, 2]] - mat[[0, 0]] - mat[[1, 1]]).sqrt() * two;
+            Self::new(
+                (mat[[0, 2]] + mat[[2, 0]]) / s,
+                (mat[[1, 2]] + mat[[2, 1]]) / s,
+                s / four,
+                (mat[[1, 0]] - mat[[0, 1]]) / s,
+            )
+        }
+    }
+
+    #[inline]
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
+    }
+
+    #[inline]
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        self.x /= mag;
+        self.y /= mag;
+        self.z /= mag;
+        self.w /= mag;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut q = *self;
+        q.normalize();
+        q
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg> From<SquareMatrix<T, 3>> for Quaternion<T> {
+    #[inline]
+    fn from(rhs: SquareMatrix<T, 3>) -> Self {
+        Self::from_matrix(&rhs)
+    }
+}
+
+impl<T: Scalar + Real + ClosedOps + ClosedNeg> From<SquareMatrix<T, 4>> for Quaternion<T> {
+    /// the rotation of the upper left 3x3 block
+    #[inline]
+    fn from(rhs: SquareMatrix<T, 4>) -> Self {
+        Self::from_matrix(&rhs.fixed_block::<3, 3>(0, 0))
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<Quaternion<T>> for SquareMatrix<T, 3> {
+    #[inline]
+    fn from(rhs: Quaternion<T>) -> Self {
+        rhs.into_matrix()
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> From<Quaternion<T>> for SquareMatrix<T, 4> {
+    #[inline]
+    fn from(rhs: Quaternion<T>) -> Self {
+        rhs.into_matrix4()
+    }
+}
+
+// the unit bivectors are the duals of the rotation axes: yz ~ -i, xz ~ j, xy ~ -k
+
+impl<T: Scalar + ClosedNeg> From<Rotor3<T>> for Quaternion<T> {
+    #[inline]
+    fn from(rhs: Rotor3<T>) -> Self {
+        let b = rhs.bivector;
+        Self::new(-b.yz, b.xz, -b.xy, rhs.scalar)
+    }
+}
+
+impl<T: Scalar + ClosedNeg> From<Quaternion<T>> for Rotor3<T> {
+    #[inline]
+    fn from(rhs: Quaternion<T>) -> Self {
+        Rotor3::new(rhs.w, Bivector3::new(-rhs.z, rhs.y, -rhs.x))
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Add for Quaternion<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl<T: Scalar + ClosedAdd> AddAssign for Quaternion<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar + ClosedSub> Sub for Quaternion<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl<T: Scalar + ClosedSub> SubAssign for Quaternion<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul for Quaternion<T> {
+    type Output = Self;
+
+    /// hamilton product
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        )
+    }
+}
+
+impl<T: Scalar + ClosedOps> MulAssign for Quaternion<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar + ClosedOps> Mul<T> for Quaternion<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl<T: Scalar + One + Zero + ClosedOps + ClosedNeg> Mul<Vector<T, 3>> for Quaternion<T> {
+    type Output = Vector<T, 3>;
+
+    fn mul(self, mut rhs: Vector<T, 3>) -> Self::Output {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar + ClosedNeg> Neg for Quaternion<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Set<Additive> for Quaternion<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self + rhs
+    }
+}
+
+impl<T: Scalar + ClosedAdd + Total<Additive>> Total<Additive> for Quaternion<T> {}
+
+impl<T: Scalar + ClosedAdd + Associative<Additive>> Associative<Additive> for Quaternion<T> {}
+
+impl<T: Scalar + Identity<Additive> + ClosedAdd> Identity<Additive> for Quaternion<T> {
+    fn identity() -> Self {
+        Self::new(T::identity(), T::identity(), T::identity(), T::identity())
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Additive>>::identity()
+    }
+}
+
+impl<T: Scalar + ClosedOps> Set<Multiplicative> for Quaternion<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self * rhs
+    }
+}
+
+impl<T: Scalar + ClosedOps + Total<Multiplicative>> Total<Multiplicative> for Quaternion<T> {}
+
+impl<T: Scalar + ClosedOps + Associative<Multiplicative>> Associative<Multiplicative>
+    for Quaternion<T>
+{
+}
+
+impl<T: Scalar + ClosedOps + Identity<Additive> + Identity<Multiplicative>> Identity<Multiplicative>
+    for Quaternion<T>
+{
+    fn identity() -> Self {
+        let zero = <T as Identity<Additive>>::identity();
+        Self::new(
+            zero,
+            zero,
+            zero,
+            <T as Identity<Multiplicative>>::identity(),
+        )
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Multiplicative>>::identity()
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> Invertible<Multiplicative> for Quaternion<T> {
+    /// the conjugate divided by the squared magnitude, which is just the conjugate for unit quaternions
+    fn inverse(&self) -> Self {
+        let magnitude_squared =
+            self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w;
+        Self::new(
+            -self.x / magnitude_squared,
+            -self.y / magnitude_squared,
+            -self.z / magnitude_squared,
+            self.w / magnitude_squared,
+        )
+    }
+
+    fn inverted(&mut self) {
+        *self = <Self as Invertible<Multiplicative>>::inverse(self);
+    }
+}
+
+#[cfg(test)]
+mod quaternion_tests {
+    use crate::algebra::linear::{Bivector3, Quaternion, Rotor3, SquareMatrix, Vector};
+    use crate::assert_approx_eq;
+    use fructose::operators::Multiplicative;
+    use fructose::properties::general::Invertible;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_3};
+
+    #[test]
+    fn rotation() {
+        let q = Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 3.0]), FRAC_PI_2);
+        assert_approx_eq!(q.magnitude(), 1.0);
+        assert_approx_eq!(
+            q * Vector::from([1.0, 0.0, 0.0]),
+            Vector::from([0.0, 1.0, 0.0]),
+            epsilon = 1e-12
+        );
+
+        let axis = Vector::from([1.0, -2.0, 0.5]);
+        let q = Quaternion::from_axis_angle(axis, FRAC_PI_3);
+        let mat = SquareMatrix::<f64, 4>::from_axis_angle(axis, FRAC_PI_3);
+        let vec = Vector::from([0.3, 1.0, -2.0]);
+        assert_approx_eq!(q * vec, vec.transform_vector(&mat), epsilon = 1e-12);
+        assert_approx_eq!(q.into_matrix4(), mat, epsilon = 1e-12);
+
+        // roll, then pitch, then yaw
+        let euler = Quaternion::from_euler_angles(0.3, -0.7, 1.1);
+        let x = Quaternion::from_axis_angle(Vector::from([1.0, 0.0, 0.0]), 0.3);
+        let y = Quaternion::from_axis_angle(Vector::from([0.0, 1.0, 0.0]), -0.7);
+        let z = Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), 1.1);
+        assert_approx_eq!(euler, z * y * x, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn algebra() {
+        let (i, j, k) = (
+            Quaternion::new(1.0, 0.0, 0.0, 0.0),
+            Quaternion::new(0.0, 1.0, 0.0, 0.0),
+            Quaternion::new(0.0, 0.0, 1.0, 0.0),
+        );
+        assert_eq!(i * j, k);
+        assert_eq!(j * i, -k);
+        assert_eq!(i * j * k, Quaternion::new(0.0, 0.0, 0.0, -1.0));
+
+        let q = Quaternion::new(1.0, 2.0, -1.0, 3.0);
+        assert_approx_eq!(q.conjugated() * q, Quaternion::new(0.0, 0.0, 0.0, 15.0));
+        let inverse = <Quaternion<f64> as Invertible<Multiplicative>>::inverse(&q);
+        assert_approx_eq!(q * inverse, Quaternion::identity(), epsilon = 1e-12);
+        assert_approx_eq!(q.normalized().magnitude(), 1.0);
+    }
+
+    #[test]
+    fn conversions() {
+        let vec = Vector::from([0.3, 1.0, -2.0]);
+        for q in [
+            Quaternion::from_euler_angles(0.3, -0.7, 1.1),
+            Quaternion::from_axis_angle(Vector::from([1.0, 0.0, 0.0]), 3.0),
+            Quaternion::from_axis_angle(Vector::from([0.0, 1.0, 0.0]), 3.0),
+            Quaternion::from_axis_angle(Vector::from([0.0, 0.0, 1.0]), 3.0),
+        ]
+        .iter()
+        {
+            let mat: SquareMatrix<f64, 3> = (*q).into();
+            let back = Quaternion::from(mat);
+            // q and -q are the same rotation
+            let back = if back.dot(*q) < 0.0 { -back } else { back };
+            assert_approx_eq!(back, *q, epsilon = 1e-12);
+            assert_approx_eq!(Quaternion::from(q.into_matrix4()), back, epsilon = 1e-12);
+
+            let rotor = Rotor3::from(*q);
+            assert_approx_eq!(rotor * vec, *q * vec, epsilon = 1e-12);
+            assert_eq!(Quaternion::from(rotor), *q);
+        }
+
+        let rotor = Rotor3::from_angle_plane(FRAC_PI_3, Bivector3::new(0.6, 0.0, 0.8));
+        assert_approx_eq!(Quaternion::from(rotor) * vec, rotor * vec, epsilon = 1e-12);
+    }
+}
@@ -1,14 +1,13 @@
-use crate::algebra::linear::bivec::Bivector2;
+use crate::algebra::linear::bivec::{Bivector2, Bivector3};
 use crate::algebra::linear::mat::Matrix;
 use crate::algebra::linear::scalar::Scalar;
 use crate::algebra::linear::vec::Vector;
 use fructose::operators::mul_add::MulAdd;
-use fructose::operators::trig::TrigOps;
-use fructose::operators::{Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedOps};
-use fructose::properties::general::{Associative, Identity, Set, Total};
-use fructose::properties::helpers::identity::{One, Two, Zero};
+use fructose::operators::{Additive, ClosedAdd, ClosedNeg, ClosedOps, Multiplicative};
+use fructose::properties::general::{Associative, Commutative, Identity, Invertible, Set, Total};
+use fructose::properties::helpers::identity::{One, Zero};
 use fructose::specific::complex::Real;
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Rotor2<S> {
@@ -77,7 +76,7 @@ impl<S: Scalar + ClosedOps + MulAdd<Output = S>> Rotor2<S> {
     }
 }
 
-impl<S: Scalar + ClosedOps + Two + ClosedNeg> Rotor2<S> {
+impl<S: Scalar + One + ClosedOps + ClosedNeg> Rotor2<S> {
     #[inline]
     pub fn rotate_vec(self, vec: &mut Vector<S, 2>) {
         let fx = self.scalar * vec[0] + self.bivector.data * vec[1];
@@ -90,7 +89,7 @@ impl<S: Scalar + ClosedOps + Two + ClosedNeg> Rotor2<S> {
     #[inline]
     pub fn into_matrix(self) -> Matrix<S, 2, 2> {
         let s2_minus_bxy2 = self.scalar * self.scalar - self.bivector.data * self.bivector.data;
-        let two_s_bxy = S::two() * self.scalar * self.bivector.data;
+        let two_s_bxy = (S::one() + S::one()) * self.scalar * self.bivector.data;
 
         Matrix::new([[s2_minus_bxy2, -two_s_bxy], [two_s_bxy, s2_minus_bxy2]])
     }
@@ -106,48 +105,97 @@ impl<S: Scalar + ClosedAdd> Add for Rotor2<S> {
 
 impl<S: Scalar + ClosedAdd> AddAssign for Rotor2<S> {
     fn add_assign(&mut self, rhs: Self) {
-        self.scalar += self.scalar;
+        self.scalar += rhs.scalar;
         self.bivector += rhs.bivector;
     }
 }
 
-impl<S: Scalar + ClosedMul + ClosedAdd + Real> Rotor2<S> {
-    // #[inline]
-    // pub fn from_rotation_between(from: Vector<S, 2>, rhs: Vector<S, 2>) -> Self {
-    //     Self::new(<S>::one() + rhs.dot(from), rhs.wedge(from)).normalized()
-    // }
-
-    // #[inline]
-    // pub fn magnitude_squared(&self) -> S {
-    //     self.scalar * self.scalar + self.bivector.magnitude_squared()
-    // }
-    // #[inline]
-    // pub fn magnitude(&self) -> S {
-    //     self.magnitude_squared().sqrt()
-    // }
-    //
-    // #[inline]
-    // pub fn normalize(&mut self) {
-    //     let mag = self.magnitude();
-    //     self.scalar /= mag;
-    //     self.bivector.data /= mag;
-    // }
-    //
-    // #[inline]
-    // pub fn normalized(&self) -> Self {
-    //     let mut rotor = *self;
-    //     rotor.normalize();
-    //     rotor
-    // }
-}
-
-impl<S: Scalar + Two + Real + ClosedDiv + TrigOps> Rotor2<S> {
+impl<S: Scalar + Real + ClosedOps + ClosedNeg> Rotor2<S> {
+    /// counter-clockwise rotation by `angle` radians
     #[inline]
     pub fn from_angle(angle: S) -> Self {
-        let half_angle = angle / <S>::two();
+        let half_angle = angle / (S::one() + S::one());
         let (sin, cos) = half_angle.sin_cos();
         Self::new(cos, Bivector2::new(-sin))
     }
+
+    /// the counter-clockwise angle in `[-π, π]` of a normalized rotor
+    #[inline]
+    pub fn angle(&self) -> S {
+        // `r` and `-r` are the same rotation, a positive scalar keeps the half angle in `[-π/2, π/2]`
+        let (scalar, bivector) = if self.scalar < S::zero() {
+            (-self.scalar, self.bivector.data)
+        } else {
+            (self.scalar, -self.bivector.data)
+        };
+        bivector.atan2(scalar) * (S::one() + S::one())
+    }
+
+    /// the shortest rotation from `from` to `to`, both have to be normalized
+    ///
+    /// undefined for opposite vectors, as there is no unique direction to rotate in.
+    #[inline]
+    pub fn from_rotation_between(from: Vector<S, 2>, to: Vector<S, 2>) -> Self {
+        Self::new(<S>::one() + to.dot(from), to.wedge(from)).normalized()
+    }
+
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.scalar * self.scalar + self.bivector.magnitude_squared()
+    }
+
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        self.scalar /= mag;
+        self.bivector.data /= mag;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut rotor = *self;
+        rotor.normalize();
+        rotor
+    }
+}
+
+impl<S: Scalar + ClosedOps> Mul for Rotor2<S> {
+    type Output = Self;
+
+    /// geometric product, composes the rotations
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.scalar, self.bivector.data);
+        let (c, d) = (rhs.scalar, rhs.bivector.data);
+        Self::new(a * c - b * d, Bivector2::new(a * d + b * c))
+    }
+}
+
+impl<S: Scalar + ClosedOps> MulAssign for Rotor2<S> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S: Scalar + One + ClosedOps + ClosedNeg> Mul<Vector<S, 2>> for Rotor2<S> {
+    type Output = Vector<S, 2>;
+
+    fn mul(self, mut rhs: Vector<S, 2>) -> Self::Output {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<S: Scalar + ClosedNeg> Neg for Rotor2<S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.scalar, -self.bivector)
+    }
 }
 
 impl<T: Scalar + ClosedAdd> Set<Additive> for Rotor2<T> {
@@ -169,3 +217,403 @@ impl<T: Scalar + Identity<Additive> + ClosedAdd> Identity<Additive> for Rotor2<T
         *self == Self::identity()
     }
 }
+
+impl<T: Scalar + ClosedOps> Set<Multiplicative> for Rotor2<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self * rhs
+    }
+}
+
+impl<T: Scalar + ClosedOps + Total<Multiplicative>> Total<Multiplicative> for Rotor2<T> {}
+
+impl<T: Scalar + ClosedOps + Associative<Multiplicative>> Associative<Multiplicative>
+    for Rotor2<T>
+{
+}
+
+impl<T: Scalar + ClosedOps + Commutative<Multiplicative>> Commutative<Multiplicative>
+    for Rotor2<T>
+{
+}
+
+impl<T: Scalar + ClosedOps + Identity<Additive> + Identity<Multiplicative>> Identity<Multiplicative>
+    for Rotor2<T>
+{
+    fn identity() -> Self {
+        Self::new(
+            <T as Identity<Multiplicative>>::identity(),
+            <Bivector2<T> as Identity<Additive>>::identity(),
+        )
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Multiplicative>>::identity()
+    }
+}
+
+impl<T: Scalar + ClosedOps + ClosedNeg> Invertible<Multiplicative> for Rotor2<T> {
+    /// the reverse divided by the squared magnitude, which is just the reverse for normalized rotors
+    fn inverse(&self) -> Self {
+        let magnitude_squared = self.scalar * self.scalar + self.bivector.data * self.bivector.data;
+        Self::new(
+            self.scalar / magnitude_squared,
+            -self.bivector / magnitude_squared,
+        )
+    }
+
+    fn inverted(&mut self) {
+        *self = <Self as Invertible<Multiplicative>>::inverse(self);
+    }
+}
+
+/// rotor in 3D, the sum of a scalar and a bivector
+///
+/// rotors are applied with the sandwich product `R * v * R^~`,
+/// `lhs * rhs` is the rotation that applies `rhs` first and `lhs` second.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor3<S> {
+    pub scalar: S,
+    pub bivector: Bivector3<S>,
+}
+
+impl<S> Rotor3<S> {
+    #[inline]
+    pub const fn new(scalar: S, bivector: Bivector3<S>) -> Self {
+        Self { scalar, bivector }
+    }
+
+    #[inline]
+    pub fn layout() -> std::alloc::Layout {
+        std::alloc::Layout::from_size_align(std::mem::size_of::<Self>(), std::mem::align_of::<S>())
+            .unwrap()
+    }
+}
+
+impl<S: Scalar + One + Zero + ClosedOps + ClosedNeg> Rotor3<S> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            scalar: <S>::one(),
+            bivector: Bivector3::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.bivector = -self.bivector;
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut s = *self;
+        s.reverse();
+        s
+    }
+
+    #[inline]
+    pub fn dot(&self, rhs: Self) -> S {
+        self.scalar * rhs.scalar + self.bivector.dot(rhs.bivector)
+    }
+
+    /// rotates this rotor by `other`: `other * self * other^~`
+    #[inline]
+    pub fn rotate_by(&mut self, other: Self) {
+        *self = other * *self * other.reversed();
+    }
+
+    #[inline]
+    pub fn rotated_by(mut self, other: Self) -> Self {
+        self.rotate_by(other);
+        self
+    }
+
+    #[inline]
+    pub fn rotate_vec(self, vec: &mut Vector<S, 3>) {
+        let (s, b) = (self.scalar, self.bivector);
+        let (x, y, z) = (vec[0], vec[1], vec[2]);
+
+        // R * v is a vector plus the trivector `xyz`
+        let fx = s * x + b.xy * y + b.xz * z;
+        let fy = s * y - b.xy * x + b.yz * z;
+        let fz = s * z - b.xz * x - b.yz * y;
+        let fxyz = b.yz * x - b.xz * y + b.xy * z;
+
+        vec[0] = s * fx + b.xy * fy + b.xz * fz + b.yz * fxyz;
+        vec[1] = s * fy - b.xy * fx - b.xz * fxyz + b.yz * fz;
+        vec[2] = s * fz + b.xy * fxyz - b.xz * fx - b.yz * fy;
+    }
+
+    /// the rotation matrix, its columns are the rotated basis vectors
+    #[inline]
+    pub fn into_matrix(self) -> Matrix<S, 3, 3> {
+        let mut mat = Matrix::<S, 3, 3>::default();
+        for j in 0..3 {
+            let mut column = Vector::<S, 3>::unit(j);
+            self.rotate_vec(&mut column);
+            mat.data[j] = column.data[0];
+        }
+        mat
+    }
+}
+
+impl<S: Scalar + Real + ClosedOps + ClosedNeg> Rotor3<S> {
+    /// rotation by `angle` radians in `plane`, which has to be normalized
+    ///
+    /// the rotation turns the first axis of the plane towards the second one,
+    /// `Bivector3::new(1, 0, 0)` rotates x towards y.
+    #[inline]
+    pub fn from_angle_plane(angle: S, plane: Bivector3<S>) -> Self {
+        let half_angle = angle / (S::one() + S::one());
+        let (sin, cos) = half_angle.sin_cos();
+        Self::new(cos, plane * -sin)
+    }
+
+    /// the shortest rotation from `from` to `to`, both have to be normalized
+    ///
+    /// undefined for opposite vectors, as there is no unique plane to rotate in.
+    #[inline]
+    pub fn from_rotation_between(from: Vector<S, 3>, to: Vector<S, 3>) -> Self {
+        Self::new(S::one() + to.dot(from), to.wedge(from)).normalized()
+    }
+
+    #[inline]
+    pub fn magnitude_squared(&self) -> S {
+        self.dot(*self)
+    }
+
+    #[inline]
+    pub fn magnitude(&self) -> S {
+        self.magnitude_squared().sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        self.scalar /= mag;
+        self.bivector /= mag;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut rotor = *self;
+        rotor.normalize();
+        rotor
+    }
+}
+
+impl<S: Scalar + ClosedAdd> Add for Rotor3<S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.scalar + rhs.scalar, self.bivector + rhs.bivector)
+    }
+}
+
+impl<S: Scalar + ClosedAdd> AddAssign for Rotor3<S> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.scalar += rhs.scalar;
+        self.bivector += rhs.bivector;
+    }
+}
+
+impl<S: Scalar + ClosedOps> Mul for Rotor3<S> {
+    type Output = Self;
+
+    /// geometric product, composes the rotations
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.scalar, self.bivector);
+        let (c, d) = (rhs.scalar, rhs.bivector);
+        Self::new(
+            a * c - b.xy * d.xy - b.xz * d.xz - b.yz * d.yz,
+            Bivector3::new(
+                b.xy * c + a * d.xy + b.yz * d.xz - b.xz * d.yz,
+                b.xz * c + a * d.xz - b.yz * d.xy + b.xy * d.yz,
+                b.yz * c + a * d.yz + b.xz * d.xy - b.xy * d.xz,
+            ),
+        )
+    }
+}
+
+impl<S: Scalar + ClosedOps> MulAssign for Rotor3<S> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<S: Scalar + One + Zero + ClosedOps + ClosedNeg> Mul<Vector<S, 3>> for Rotor3<S> {
+    type Output = Vector<S, 3>;
+
+    fn mul(self, mut rhs: Vector<S, 3>) -> Self::Output {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Set<Additive> for Rotor3<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self + rhs
+    }
+}
+
+impl<T: Scalar + ClosedAdd + Total<Additive>> Total<Additive> for Rotor3<T> {}
+
+impl<T: Scalar + ClosedAdd + Associative<Additive>> Associative<Additive> for Rotor3<T> {}
+
+impl<T: Scalar + Identity<Additive> + ClosedAdd> Identity<Additive> for Rotor3<T> {
+    fn identity() -> Self {
+        Self::new(T::identity(), Bivector3::identity())
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Additive>>::identity()
+    }
+}
+
+impl<T: Scalar + ClosedOps> Set<Multiplicative> for Rotor3<T> {
+    fn operate(&self, rhs: Self) -> Self {
+        *self * rhs
+    }
+}
+
+impl<T: Scalar + ClosedOps + Total<Multiplicative>> Total<Multiplicative> for Rotor3<T> {}
+
+impl<T: Scalar + ClosedOps + Associative<Multiplicative>> Associative<Multiplicative>
+    for Rotor3<T>
+{
+}
+
+impl<T: Scalar + ClosedOps + Identity<Additive> + Identity<Multiplicative>> Identity<Multiplicative>
+    for Rotor3<T>
+{
+    fn identity() -> Self {
+        Self::new(
+            <T as Identity<Multiplicative>>::identity(),
+            <Bivector3<T> as Identity<Additive>>::identity(),
+        )
+    }
+
+    fn is_identity(&self) -> bool {
+        *self == <Self as Identity<Multiplicative>>::identity()
+    }
+}
+
+#[cfg(test)]
+mod rotor_tests {
+    use crate::algebra::linear::{Bivector2, Bivector3, Rotor2, Rotor3, SquareMatrix, Vector};
+    use crate::assert_approx_eq;
+    use fructose::operators::Multiplicative;
+    use fructose::properties::general::{Identity, Invertible};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    #[test]
+    fn rotor2_rotation() {
+        let rotor = Rotor2::from_angle(FRAC_PI_2);
+        assert_approx_eq!(
+            rotor * Vector::from([1.0, 0.0]),
+            Vector::from([0.0, 1.0]),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(
+            rotor.into_matrix() * Vector::from([2.0, 1.0]),
+            rotor * Vector::from([2.0, 1.0]),
+            epsilon = 1e-12
+        );
+
+        let from = Vector::from([3.0, 4.0]).normalized();
+        let to = Vector::from([-1.0, 1.0]).normalized();
+        let rotor = Rotor2::from_rotation_between(from, to);
+        assert_approx_eq!(rotor.magnitude(), 1.0);
+        assert_approx_eq!(rotor * from, to, epsilon = 1e-12);
+
+        let mut scaled = Rotor2::new(3.0, Bivector2::new(-4.0));
+        assert_approx_eq!(scaled.magnitude(), 5.0);
+        scaled.normalize();
+        assert_approx_eq!(scaled, Rotor2::new(0.6, Bivector2::new(-0.8)));
+    }
+
+    #[test]
+    fn rotor2_group() {
+        let a = Rotor2::from_angle(FRAC_PI_4);
+        let b = Rotor2::from_angle(FRAC_PI_2);
+        assert_approx_eq!(
+            a * b,
+            Rotor2::from_angle(FRAC_PI_2 + FRAC_PI_4),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(a * b, b * a, epsilon = 1e-12);
+
+        let identity = <Rotor2<f64> as Identity<Multiplicative>>::identity();
+        assert_eq!(identity, Rotor2::new(1.0, Bivector2::new(0.0)));
+        assert_approx_eq!(a * identity, a);
+
+        let scaled = Rotor2::new(2.0, Bivector2::new(1.0));
+        let inverse = <Rotor2<f64> as Invertible<Multiplicative>>::inverse(&scaled);
+        assert_approx_eq!(scaled * inverse, identity, epsilon = 1e-12);
+        assert_approx_eq!(
+            <Rotor2<f64> as Invertible<Multiplicative>>::inverse(&a),
+            a.reversed(),
+            epsilon = 1e-12
+        );
+
+        let mut bivector = Bivector2::new(4.0);
+        <Bivector2<f64> as Invertible<Multiplicative>>::inverted(&mut bivector);
+        assert_eq!(bivector, Bivector2::new(0.25));
+    }
+
+    #[test]
+    fn rotor3_rotation() {
+        let rotor = Rotor3::from_angle_plane(FRAC_PI_2, Bivector3::new(1.0, 0.0, 0.0));
+        assert_approx_eq!(
+            rotor * Vector::from([1.0, 0.0, 0.0]),
+            Vector::from([0.0, 1.0, 0.0]),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(
+            rotor * Vector::from([0.0, 0.0, 1.0]),
+            Vector::from([0.0, 0.0, 1.0]),
+            epsilon = 1e-12
+        );
+
+        let from = Vector::from([1.0, 2.0, 2.0]).normalized();
+        let to = Vector::from([0.0, -3.0, 4.0]).normalized();
+        let rotor = Rotor3::from_rotation_between(from, to);
+        assert_approx_eq!(rotor.magnitude(), 1.0);
+        assert_approx_eq!(rotor * from, to, epsilon = 1e-12);
+        assert_approx_eq!(rotor.reversed() * to, from, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn rotor3_composition() {
+        let xy = Rotor3::from_angle_plane(FRAC_PI_2, Bivector3::new(1.0, 0.0, 0.0));
+        let yz = Rotor3::from_angle_plane(FRAC_PI_2, Bivector3::new(0.0, 0.0, 1.0));
+        let vec = Vector::from([1.0, 2.0, 3.0]);
+
+        // x -> y, then y -> z
+        let combined = yz * xy;
+        assert_approx_eq!(combined * vec, yz * (xy * vec), epsilon = 1e-12);
+        assert_approx_eq!(
+            combined * Vector::from([1.0, 0.0, 0.0]),
+            Vector::from([0.0, 0.0, 1.0]),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(
+            combined * combined.reversed(),
+            Rotor3::identity(),
+            epsilon = 1e-12
+        );
+
+        let matrix = combined.into_matrix();
+        assert_approx_eq!(matrix * vec, combined * vec, epsilon = 1e-12);
+        assert_approx_eq!(
+            matrix.transpose() * matrix,
+            SquareMatrix::mul_identity(),
+            epsilon = 1e-12
+        );
+        assert_approx_eq!(matrix.determinant(), 1.0, epsilon = 1e-12);
+
+        let mut scaled = Rotor3::new(2.0, Bivector3::new(0.0, 2.0, 0.0));
+        scaled.normalize();
+        assert_approx_eq!(scaled.magnitude(), 1.0);
+    }
+}
@@ -0,0 +1,747 @@
+//! matrices that only store their nonzero entries
+//!
+//! `CooMatrix` collects `(row, column, value)` triplets in any order and is meant for assembling
+//! a matrix, `CsrMatrix` and `CscMatrix` compress it row by row and column by column for
+//! arithmetic. The compressed rows of a matrix are the compressed columns of its transpose,
+//! so both formats share one representation.
+use crate::algebra::linear::{DMatrix, GenericMatrix, Scalar, Storage, StorageMut};
+use fructose::operators::{ClosedAdd, ClosedMul};
+use std::ops::Mul;
+
+/// a sparse matrix as a list of `(row, column, value)` triplets
+///
+/// the triplets may come in any order, entries given more than once are summed up when
+/// converting into a compressed format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    shape: (usize, usize),
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    /// an empty matrix with `shape.0` rows and `shape.1` columns
+    #[inline]
+    pub fn new(shape: (usize, usize)) -> Self {
+        Self::with_capacity(shape, 0)
+    }
+
+    #[inline]
+    pub fn with_capacity(shape: (usize, usize), capacity: usize) -> Self {
+        Self {
+            shape,
+            rows: Vec::with_capacity(capacity),
+            columns: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// adds `value` to entry `[row, column]`, panics if it is out of bounds
+    #[inline]
+    pub fn push(&mut self, row: usize, column: usize, value: T) {
+        assert!(
+            row < self.shape.0 && column < self.shape.1,
+            "entry is out of bounds"
+        );
+        self.rows.push(row);
+        self.columns.push(column);
+        self.values.push(value);
+    }
+
+    /// `(rows, columns)`
+    #[inline]
+    pub fn size(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    /// the number of stored triplets, including duplicates
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// the triplets in the order they were pushed
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.rows
+            .iter()
+            .zip(self.columns.iter())
+            .zip(self.values.iter())
+            .map(|((row, column), value)| (*row, *column, value))
+    }
+}
+
+/// entries compressed along their major dimension
+///
+/// lane `i` has its minor indices in ascending order in `indices[offsets[i]..offsets[i + 1]]`
+/// and its entries at the same positions of `values`.
+#[derive(Debug, Clone, PartialEq)]
+struct Compressed<T> {
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+    minor: usize,
+}
+
+impl<T> Compressed<T> {
+    /// checks the invariants, panics if one of them doesn't hold
+    fn new(offsets: Vec<usize>, indices: Vec<usize>, values: Vec<T>, minor: usize) -> Self {
+        assert!(
+            !offsets.is_empty() && offsets[0] == 0,
+            "offsets have to start at 0"
+        );
+        assert_eq!(indices.len(), values.len());
+        assert_eq!(*offsets.last().unwrap(), indices.len());
+        for lane in offsets.windows(2) {
+            assert!(lane[0] <= lane[1], "offsets have to be ascending");
+            let indices = &indices[lane[0]..lane[1]];
+            assert!(
+                indices.windows(2).all(|pair| pair[0] < pair[1]),
+                "indices of a lane have to be strictly ascending"
+            );
+            assert!(indices.iter().all(|i| *i < minor), "index is out of bounds");
+        }
+        Self {
+            offsets,
+            indices,
+            values,
+            minor,
+        }
+    }
+
+    #[inline]
+    fn major(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    #[inline]
+    fn lane(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.offsets[i]..self.offsets[i + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    #[inline]
+    fn get(&self, major: usize, minor: usize) -> Option<&T> {
+        let (indices, values) = self.lane(major);
+        indices.binary_search(&minor).ok().map(|k| &values[k])
+    }
+}
+
+impl<T: Scalar> Compressed<T> {
+    /// every entry of a `major`x`minor` matrix that is not `T::default()`
+    fn from_fn<F: Fn(usize, usize) -> T>(major: usize, minor: usize, entry: F) -> Self {
+        let mut offsets = Vec::with_capacity(major + 1);
+        let (mut indices, mut values) = (Vec::new(), Vec::new());
+        offsets.push(0);
+        for i in 0..major {
+            for j in 0..minor {
+                let value = entry(i, j);
+                if value != T::default() {
+                    indices.push(j);
+                    values.push(value);
+                }
+            }
+            offsets.push(indices.len());
+        }
+        Self {
+            offsets,
+            indices,
+            values,
+            minor,
+        }
+    }
+
+    /// the same entries compressed along the other dimension, which is the transpose
+    fn transpose(&self) -> Self {
+        let mut offsets = vec![0; self.minor + 1];
+        self.indices.iter().for_each(|j| offsets[j + 1] += 1);
+        for j in 0..self.minor {
+            offsets[j + 1] += offsets[j];
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![T::default(); self.values.len()];
+        // walking the lanes in order keeps the new lanes sorted
+        for i in 0..self.major() {
+            let (lane_indices, lane_values) = self.lane(i);
+            for (j, value) in lane_indices.iter().zip(lane_values.iter()) {
+                indices[next[*j]] = i;
+                values[next[*j]] = *value;
+                next[*j] += 1;
+            }
+        }
+
+        Self {
+            offsets,
+            indices,
+            values,
+            minor: self.major(),
+        }
+    }
+
+    /// writes the entry at `[major, minor]` of every stored value into `out`
+    fn to_dense<F: FnMut(usize, usize, T)>(&self, mut out: F) {
+        for i in 0..self.major() {
+            let (indices, values) = self.lane(i);
+            indices
+                .iter()
+                .zip(values.iter())
+                .for_each(|(j, value)| out(i, *j, *value));
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd> Compressed<T> {
+    /// compresses the triplets along `majors`, summing duplicates
+    fn from_triplets(
+        shape: (usize, usize),
+        majors: &[usize],
+        minors: &[usize],
+        values: &[T],
+    ) -> Self {
+        let mut order = (0..values.len()).collect::<Vec<_>>();
+        order.sort_by_key(|k| (majors[*k], minors[*k]));
+
+        let mut offsets = vec![0; shape.0 + 1];
+        let mut indices = Vec::with_capacity(values.len());
+        let mut compressed = Vec::<T>::with_capacity(values.len());
+        let mut last = None;
+        for k in order {
+            let (i, j) = (majors[k], minors[k]);
+            if last == Some((i, j)) {
+                *compressed.last_mut().unwrap() += values[k];
+                continue;
+            }
+            last = Some((i, j));
+            offsets[i + 1] += 1;
+            indices.push(j);
+            compressed.push(values[k]);
+        }
+        for i in 0..shape.0 {
+            offsets[i + 1] += offsets[i];
+        }
+
+        Self {
+            offsets,
+            indices,
+            values: compressed,
+            minor: shape.1,
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> Compressed<T> {
+    /// the product of the matrices whose rows are the lanes of `self` and `rhs`
+    fn mul(&self, rhs: &Self) -> Self {
+        assert_eq!(
+            self.minor,
+            rhs.major(),
+            "inner dimensions of a matrix product have to match"
+        );
+
+        // accumulates one lane of the product, `touched` remembers which of its entries are used
+        let mut accumulator = vec![T::default(); rhs.minor];
+        let mut used = vec![false; rhs.minor];
+        let mut touched = Vec::new();
+
+        let mut offsets = Vec::with_capacity(self.major() + 1);
+        let (mut indices, mut values) = (Vec::new(), Vec::new());
+        offsets.push(0);
+        for i in 0..self.major() {
+            let (lhs_indices, lhs_values) = self.lane(i);
+            for (k, lhs) in lhs_indices.iter().zip(lhs_values.iter()) {
+                let (rhs_indices, rhs_values) = rhs.lane(*k);
+                for (j, rhs) in rhs_indices.iter().zip(rhs_values.iter()) {
+                    if !used[*j] {
+                        used[*j] = true;
+                        touched.push(*j);
+                    }
+                    accumulator[*j] += *lhs * *rhs;
+                }
+            }
+
+            touched.sort_unstable();
+            for j in touched.drain(..) {
+                indices.push(j);
+                values.push(accumulator[j]);
+                accumulator[j] = T::default();
+                used[j] = false;
+            }
+            offsets.push(indices.len());
+        }
+
+        Self {
+            offsets,
+            indices,
+            values,
+            minor: rhs.minor,
+        }
+    }
+}
+
+/// a sparse matrix in compressed sparse row format
+///
+/// row `i` has the column indices `column_indices()[row_offsets()[i]..row_offsets()[i + 1]]`
+/// in ascending order, its entries are at the same positions of `values()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    data: Compressed<T>,
+}
+
+/// a sparse matrix in compressed sparse column format
+///
+/// column `j` has the row indices `row_indices()[column_offsets()[j]..column_offsets()[j + 1]]`
+/// in ascending order, its entries are at the same positions of `values()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    data: Compressed<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// builds a matrix out of its raw parts, panics if they are not a valid `rows`x`columns` matrix
+    #[inline]
+    pub fn from_parts(
+        columns: usize,
+        row_offsets: Vec<usize>,
+        column_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            data: Compressed::new(row_offsets, column_indices, values, columns),
+        }
+    }
+
+    /// `(rows, columns)`
+    #[inline]
+    pub fn size(&self) -> (usize, usize) {
+        (self.data.major(), self.data.minor)
+    }
+
+    /// the number of stored entries
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    #[inline]
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    #[inline]
+    pub fn column_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// the column indices and entries of row `i`
+    #[inline]
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        self.data.lane(i)
+    }
+
+    /// entry `[i, j]` if it is stored
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.data.get(i, j)
+    }
+}
+
+impl<T> CscMatrix<T> {
+    /// builds a matrix out of its raw parts, panics if they are not a valid `rows`x`columns` matrix
+    #[inline]
+    pub fn from_parts(
+        rows: usize,
+        column_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Self {
+        Self {
+            data: Compressed::new(column_offsets, row_indices, values, rows),
+        }
+    }
+
+    /// `(rows, columns)`
+    #[inline]
+    pub fn size(&self) -> (usize, usize) {
+        (self.data.minor, self.data.major())
+    }
+
+    /// the number of stored entries
+    #[inline]
+    pub fn nnz(&self) -> usize {
+        self.data.values.len()
+    }
+
+    #[inline]
+    pub fn column_offsets(&self) -> &[usize] {
+        &self.data.offsets
+    }
+
+    #[inline]
+    pub fn row_indices(&self) -> &[usize] {
+        &self.data.indices
+    }
+
+    #[inline]
+    pub fn values(&self) -> &[T] {
+        &self.data.values
+    }
+
+    /// the row indices and entries of column `j`
+    #[inline]
+    pub fn column(&self, j: usize) -> (&[usize], &[T]) {
+        self.data.lane(j)
+    }
+
+    /// entry `[i, j]` if it is stored
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.data.get(j, i)
+    }
+}
+
+impl<T: Scalar> CsrMatrix<T> {
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self {
+            data: self.data.transpose(),
+        }
+    }
+}
+
+impl<T: Scalar> CscMatrix<T> {
+    #[inline]
+    pub fn transpose(&self) -> Self {
+        Self {
+            data: self.data.transpose(),
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd> From<&CooMatrix<T>> for CsrMatrix<T> {
+    #[inline]
+    fn from(rhs: &CooMatrix<T>) -> Self {
+        Self {
+            data: Compressed::from_triplets(rhs.shape, &rhs.rows, &rhs.columns, &rhs.values),
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd> From<&CooMatrix<T>> for CscMatrix<T> {
+    #[inline]
+    fn from(rhs: &CooMatrix<T>) -> Self {
+        let shape = (rhs.shape.1, rhs.shape.0);
+        Self {
+            data: Compressed::from_triplets(shape, &rhs.columns, &rhs.rows, &rhs.values),
+        }
+    }
+}
+
+impl<T: Scalar> From<&CsrMatrix<T>> for CscMatrix<T> {
+    #[inline]
+    fn from(rhs: &CsrMatrix<T>) -> Self {
+        Self {
+            data: rhs.data.transpose(),
+        }
+    }
+}
+
+impl<T: Scalar> From<&CscMatrix<T>> for CsrMatrix<T> {
+    #[inline]
+    fn from(rhs: &CscMatrix<T>) -> Self {
+        Self {
+            data: rhs.data.transpose(),
+        }
+    }
+}
+
+/// keeps every entry that is not `T::default()`
+impl<T: Scalar, S: Storage<Element = T>> From<&GenericMatrix<S>> for CsrMatrix<T> {
+    fn from(rhs: &GenericMatrix<S>) -> Self {
+        let (rows, columns) = rhs.size();
+        Self {
+            data: Compressed::from_fn(rows, columns, |i, j| rhs[[i, j]]),
+        }
+    }
+}
+
+/// keeps every entry that is not `T::default()`
+impl<T: Scalar, S: Storage<Element = T>> From<&GenericMatrix<S>> for CscMatrix<T> {
+    fn from(rhs: &GenericMatrix<S>) -> Self {
+        let (rows, columns) = rhs.size();
+        Self {
+            data: Compressed::from_fn(columns, rows, |j, i| rhs[[i, j]]),
+        }
+    }
+}
+
+impl<T: Scalar> From<&CsrMatrix<T>> for DMatrix<T> {
+    fn from(rhs: &CsrMatrix<T>) -> Self {
+        let mut mat = DMatrix::from_element(rhs.size(), T::default());
+        rhs.data.to_dense(|i, j, value| mat[[i, j]] = value);
+        mat
+    }
+}
+
+impl<T: Scalar> From<&CscMatrix<T>> for DMatrix<T> {
+    fn from(rhs: &CscMatrix<T>) -> Self {
+        let mut mat = DMatrix::from_element(rhs.size(), T::default());
+        rhs.data.to_dense(|j, i, value| mat[[i, j]] = value);
+        mat
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> CsrMatrix<T> {
+    /// writes `self * rhs` into `out`, which needs as many rows as `self` and as many columns as `rhs`
+    pub fn mul_to<R: Storage<Element = T>, O: StorageMut<Element = T>>(
+        &self,
+        rhs: &GenericMatrix<R>,
+        out: &mut GenericMatrix<O>,
+    ) {
+        let (m, n) = self.size();
+        let (rhs_rows, p) = rhs.size();
+        assert_eq!(
+            n, rhs_rows,
+            "inner dimensions of a matrix product have to match"
+        );
+        assert_eq!(out.size(), (m, p));
+
+        for j in 0..p {
+            for i in 0..m {
+                let (indices, values) = self.row(i);
+                let mut sum = T::default();
+                for (k, value) in indices.iter().zip(values.iter()) {
+                    sum += *value * rhs[[*k, j]];
+                }
+                out[[i, j]] = sum;
+            }
+        }
+    }
+}
+
+impl<T: Scalar + ClosedAdd + ClosedMul> CscMatrix<T> {
+    /// writes `self * rhs` into `out`, which needs as many rows as `self` and as many columns as `rhs`
+    pub fn mul_to<R: Storage<Element = T>, O: StorageMut<Element = T>>(
+        &self,
+        rhs: &GenericMatrix<R>,
+        out: &mut GenericMatrix<O>,
+    ) {
+        let (m, n) = self.size();
+        let (rhs_rows, p) = rhs.size();
+        assert_eq!(
+            n, rhs_rows,
+            "inner dimensions of a matrix product have to match"
+        );
+        assert_eq!(out.size(), (m, p));
+
+        out.apply(|_| T::default());
+        for j in 0..p {
+            for k in 0..n {
+                let factor = rhs[[k, j]];
+                let (indices, values) = self.column(k);
+                for (i, value) in indices.iter().zip(values.iter()) {
+                    out[[*i, j]] += *value * factor;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_sparse_ops {
+    ($($sparse:ident, $swapped:expr);*) => {
+        $(
+            impl<'a, 'r, T: Scalar + ClosedAdd + ClosedMul, R: Storage<Element = T>>
+                Mul<&'r GenericMatrix<R>> for &'a $sparse<T>
+            {
+                type Output = DMatrix<T>;
+
+                #[inline]
+                fn mul(self, rhs: &'r GenericMatrix<R>) -> Self::Output {
+                    let mut mat = DMatrix::from_element((self.size().0, rhs.size().1), T::default());
+                    self.mul_to(rhs, &mut mat);
+                    mat
+                }
+            }
+
+            impl<'r, T: Scalar + ClosedAdd + ClosedMul, R: Storage<Element = T>>
+                Mul<&'r GenericMatrix<R>> for $sparse<T>
+            {
+                type Output = DMatrix<T>;
+
+                #[inline]
+                fn mul(self, rhs: &'r GenericMatrix<R>) -> Self::Output {
+                    &self * rhs
+                }
+            }
+
+            impl<'a, 'r, T: Scalar + ClosedAdd + ClosedMul> Mul<&'r $sparse<T>> for &'a $sparse<T> {
+                type Output = $sparse<T>;
+
+                #[inline]
+                fn mul(self, rhs: &'r $sparse<T>) -> Self::Output {
+                    let (lhs, rhs) = if $swapped { (rhs, self) } else { (self, rhs) };
+                    $sparse {
+                        data: lhs.data.mul(&rhs.data),
+                    }
+                }
+            }
+
+            impl<'r, T: Scalar + ClosedAdd + ClosedMul> Mul<&'r $sparse<T>> for $sparse<T> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: &'r $sparse<T>) -> Self::Output {
+                    &self * rhs
+                }
+            }
+        )*
+    };
+}
+
+// the CSC lanes of `A` are the CSR lanes of `A^T` and `(A * B)^T = B^T * A^T`,
+// so CSC products multiply the lanes the other way around
+impl_sparse_ops!(
+    CsrMatrix, false;
+    CscMatrix, true
+);
+
+#[cfg(test)]
+mod sparse_tests {
+    use crate::algebra::linear::{CooMatrix, CscMatrix, CsrMatrix, DMatrix, Matrix};
+
+    fn coo() -> CooMatrix<i32> {
+        // [[1, 0, 2, 0],
+        //  [0, 0, 3, 0],
+        //  [4, 5, 0, 6]]
+        let mut coo = CooMatrix::new((3, 4));
+        coo.push(2, 3, 6);
+        coo.push(0, 2, 2);
+        coo.push(2, 0, 4);
+        coo.push(1, 2, 1);
+        coo.push(0, 0, 1);
+        coo.push(2, 1, 5);
+        coo.push(1, 2, 2);
+        coo
+    }
+
+    fn dense() -> DMatrix<i32> {
+        DMatrix::from(Matrix::<i32, 3, 4>::new([
+            [1, 0, 4],
+            [0, 0, 5],
+            [2, 3, 0],
+            [0, 0, 6],
+        ]))
+    }
+
+    #[test]
+    fn build() {
+        let coo = coo();
+        assert_eq!(coo.nnz(), 7);
+        assert_eq!(coo.triplets().next(), Some((2, 3, &6)));
+
+        let csr = CsrMatrix::from(&coo);
+        assert_eq!(csr.size(), (3, 4));
+        assert_eq!(csr.nnz(), 6);
+        assert_eq!(csr.row_offsets(), [0, 2, 3, 6]);
+        assert_eq!(csr.column_indices(), [0, 2, 2, 0, 1, 3]);
+        assert_eq!(csr.values(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(csr.row(1), (&[2][..], &[3][..]));
+        assert_eq!((csr.get(1, 2), csr.get(1, 1)), (Some(&3), None));
+
+        let csc = CscMatrix::from(&coo);
+        assert_eq!(csc.size(), (3, 4));
+        assert_eq!(csc.column_offsets(), [0, 2, 3, 5, 6]);
+        assert_eq!(csc.row_indices(), [0, 2, 2, 0, 1, 2]);
+        assert_eq!(csc.values(), [1, 4, 5, 2, 3, 6]);
+        assert_eq!((csc.get(2, 1), csc.get(0, 1)), (Some(&5), None));
+
+        assert_eq!(
+            CsrMatrix::from_parts(
+                4,
+                vec![0, 2, 3, 6],
+                vec![0, 2, 2, 0, 1, 3],
+                vec![1, 2, 3, 4, 5, 6]
+            ),
+            csr
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let csr = CsrMatrix::from(&coo());
+        let csc = CscMatrix::from(&coo());
+        assert_eq!(DMatrix::from(&csr), dense());
+        assert_eq!(DMatrix::from(&csc), dense());
+        assert_eq!(CsrMatrix::from(&dense()), csr);
+        assert_eq!(CscMatrix::from(&dense()), csc);
+        assert_eq!(CscMatrix::from(&csr), csc);
+        assert_eq!(CsrMatrix::from(&csc), csr);
+        assert_eq!(CsrMatrix::from(&dense().transpose_view()), csr.transpose());
+    }
+
+    #[test]
+    fn transpose() {
+        let csr = CsrMatrix::from(&coo());
+        let transposed = csr.transpose();
+        assert_eq!(transposed.size(), (4, 3));
+        assert_eq!(
+            DMatrix::from(&transposed),
+            dense().transpose_view().clone_owned()
+        );
+        assert_eq!(transposed.transpose(), csr);
+
+        let csc = CscMatrix::from(&coo());
+        assert_eq!(
+            DMatrix::from(&csc.transpose()),
+            dense().transpose_view().clone_owned()
+        );
+    }
+
+    #[test]
+    fn multiplication() {
+        let csr = CsrMatrix::from(&coo());
+        let csc = CscMatrix::from(&coo());
+        let rhs = DMatrix::from(Matrix::<i32, 4, 2>::new([[1, -1, 0, 2], [3, 0, 1, 1]]));
+        let product = dense() * &rhs;
+        assert_eq!(&csr * &rhs, product);
+        assert_eq!(&csc * &rhs, product);
+        assert_eq!(csr.clone() * &rhs.as_view(), product);
+
+        let transposed = csr.transpose();
+        let gram = dense() * &dense().transpose_view();
+        assert_eq!(DMatrix::from(&(&csr * &transposed)), gram);
+        assert_eq!(
+            DMatrix::from(&(csc.clone() * &CscMatrix::from(&transposed))),
+            gram
+        );
+        assert_eq!(
+            DMatrix::from(&(&transposed * &csr)),
+            dense().transpose_view() * &dense()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        CooMatrix::new((2, 2)).push(2, 0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_parts() {
+        CsrMatrix::from_parts(2, vec![0, 2], vec![1, 0], vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_shapes() {
+        let csr = CsrMatrix::from(&coo());
+        let _ = &csr * &csr;
+    }
+}
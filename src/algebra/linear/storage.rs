@@ -1 +1,406 @@
-// TODO: merge DMatrix and Matrix via a storage trait & types
+//! the buffers a `GenericMatrix` can keep its entries in
+//!
+//! entry `[m, n]` lives at `m * strides.0 + n * strides.1` of `as_slice`. Owned storages are
+//! always contiguous and column-major, borrowed ones may use any strides, which is how
+//! transposed and sliced views are expressed without copying.
+
+/// read access to the entries of a matrix
+pub trait Storage {
+    type Element;
+
+    /// `(rows, columns)`
+    fn shape(&self) -> (usize, usize);
+
+    /// `(row stride, column stride)`, how far apart neighbouring entries of a column and of a row are
+    #[inline]
+    fn strides(&self) -> (usize, usize) {
+        (1, self.shape().0)
+    }
+
+    /// the buffer the entries live in
+    fn as_slice(&self) -> &[Self::Element];
+
+    /// whether the entries are stored column by column without gaps
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        let (rows, columns) = self.shape();
+        let (row_stride, column_stride) = self.strides();
+        (rows <= 1 || row_stride == 1) && (columns <= 1 || column_stride == rows)
+    }
+}
+
+/// write access to the entries of a matrix
+pub trait StorageMut: Storage {
+    fn as_mut_slice(&mut self) -> &mut [Self::Element];
+}
+
+/// a storage that owns its entries, operators on matrices backed by one return a new matrix of the same type
+///
+/// owned storages are contiguous, their strides are always `(1, rows)`.
+pub trait OwnedStorage: StorageMut + Clone {
+    /// a storage of the given shape with every entry set to `value`
+    ///
+    /// fixed size storages panic if `shape` is not their size.
+    fn from_element(shape: (usize, usize), value: Self::Element) -> Self;
+}
+
+/// the entries of a matrix with `M` rows and `N` columns, stored inline as `N` columns
+pub type ArrayStorage<T, const M: usize, const N: usize> = [[T; M]; N];
+
+impl<T, const M: usize, const N: usize> Storage for [[T; M]; N] {
+    type Element = T;
+
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        (M, N)
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        // this is safe because nested arrays are laid out contiguously without padding
+        unsafe { std::slice::from_raw_parts(self.as_ptr() as *const T, M * N) }
+    }
+}
+
+impl<T, const M: usize, const N: usize> StorageMut for [[T; M]; N] {
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr() as *mut T, M * N) }
+    }
+}
+
+impl<T: Copy, const M: usize, const N: usize> OwnedStorage for [[T; M]; N] {
+    #[inline]
+    fn from_element(shape: (usize, usize), value: T) -> Self {
+        assert_eq!(shape, (M, N));
+        [[value; M]; N]
+    }
+}
+
+/// the entries of a matrix whose size is only known at runtime, stored on the heap
+#[derive(Debug, Clone, PartialEq)]
+pub struct VecStorage<T> {
+    data: Vec<T>,
+    shape: (usize, usize),
+}
+
+impl<T> VecStorage<T> {
+    /// `data` holds the entries column by column, panics if it doesn't have `rows * columns` of them
+    #[inline]
+    pub fn new(data: Vec<T>, shape: (usize, usize)) -> Self {
+        assert_eq!(data.len(), shape.0 * shape.1);
+        Self { data, shape }
+    }
+
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl<T> Default for VecStorage<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            shape: (0, 0),
+        }
+    }
+}
+
+impl<T> Storage for VecStorage<T> {
+    type Element = T;
+
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T> StorageMut for VecStorage<T> {
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T: Clone> OwnedStorage for VecStorage<T> {
+    #[inline]
+    fn from_element(shape: (usize, usize), value: T) -> Self {
+        Self {
+            data: vec![value; shape.0 * shape.1],
+            shape,
+        }
+    }
+}
+
+/// panics if an entry of a matrix with the given shape and strides would lie outside of `len`
+fn check_bounds(len: usize, shape: (usize, usize), strides: (usize, usize)) {
+    if shape.0 == 0 || shape.1 == 0 {
+        return;
+    }
+    let last = (shape.0 - 1) * strides.0 + (shape.1 - 1) * strides.1;
+    assert!(last < len, "strided matrix exceeds its buffer");
+}
+
+/// whether both storages have the same shape and equal entries, regardless of their layout
+fn entries_eq<A: Storage, B: Storage>(lhs: &A, rhs: &B) -> bool
+where
+    A::Element: PartialEq<B::Element>,
+{
+    let (rows, columns) = lhs.shape();
+    if rhs.shape() != (rows, columns) {
+        return false;
+    }
+    let (lhs_strides, rhs_strides) = (lhs.strides(), rhs.strides());
+    let (lhs_slice, rhs_slice) = (lhs.as_slice(), rhs.as_slice());
+    (0..columns).all(|n| {
+        (0..rows).all(|m| {
+            lhs_slice[m * lhs_strides.0 + n * lhs_strides.1]
+                == rhs_slice[m * rhs_strides.0 + n * rhs_strides.1]
+        })
+    })
+}
+
+/// entries borrowed from a slice
+///
+/// two of them are equal if their entries are, the rest of the borrowed buffer is ignored.
+#[derive(Debug, Copy, Clone)]
+pub struct SliceStorage<'a, T> {
+    data: &'a [T],
+    shape: (usize, usize),
+    strides: (usize, usize),
+}
+
+impl<'a, T> SliceStorage<'a, T> {
+    /// `data` holds the entries column by column, panics if it doesn't have `rows * columns` of them
+    #[inline]
+    pub fn new(data: &'a [T], shape: (usize, usize)) -> Self {
+        assert_eq!(data.len(), shape.0 * shape.1);
+        Self::with_strides(data, shape, (1, shape.0))
+    }
+
+    /// entry `[m, n]` is `data[m * strides.0 + n * strides.1]`, panics if that is out of bounds
+    #[inline]
+    pub fn with_strides(data: &'a [T], shape: (usize, usize), strides: (usize, usize)) -> Self {
+        check_bounds(data.len(), shape, strides);
+        Self {
+            data,
+            shape,
+            strides,
+        }
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for SliceStorage<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        entries_eq(self, other)
+    }
+}
+
+impl<'a, T> Storage for SliceStorage<'a, T> {
+    type Element = T;
+
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    #[inline]
+    fn strides(&self) -> (usize, usize) {
+        self.strides
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self.data
+    }
+}
+
+/// entries mutably borrowed from a slice
+///
+/// two of them are equal if their entries are, the rest of the borrowed buffer is ignored.
+#[derive(Debug)]
+pub struct SliceStorageMut<'a, T> {
+    data: &'a mut [T],
+    shape: (usize, usize),
+    strides: (usize, usize),
+}
+
+impl<'a, T> SliceStorageMut<'a, T> {
+    /// `data` holds the entries column by column, panics if it doesn't have `rows * columns` of them
+    #[inline]
+    pub fn new(data: &'a mut [T], shape: (usize, usize)) -> Self {
+        assert_eq!(data.len(), shape.0 * shape.1);
+        Self::with_strides(data, shape, (1, shape.0))
+    }
+
+    /// entry `[m, n]` is `data[m * strides.0 + n * strides.1]`, panics if that is out of bounds
+    #[inline]
+    pub fn with_strides(data: &'a mut [T], shape: (usize, usize), strides: (usize, usize)) -> Self {
+        check_bounds(data.len(), shape, strides);
+        Self {
+            data,
+            shape,
+            strides,
+        }
+    }
+}
+
+impl<'a, T: PartialEq> PartialEq for SliceStorageMut<'a, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        entries_eq(self, other)
+    }
+}
+
+impl<'a, T> Storage for SliceStorageMut<'a, T> {
+    type Element = T;
+
+    #[inline]
+    fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+
+    #[inline]
+    fn strides(&self) -> (usize, usize) {
+        self.strides
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<'a, T> StorageMut for SliceStorageMut<'a, T> {
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod storage_tests {
+    use crate::algebra::linear::{
+        DMatrix, GenericMatrix, Matrix, SliceStorage, SliceStorageMut, Storage,
+    };
+
+    #[test]
+    fn layout() {
+        let mat = Matrix::<i32, 2, 3>::new([[1, 2], [3, 4], [5, 6]]);
+        let dmat = DMatrix::from(mat);
+        assert_eq!(mat.data.shape(), (2, 3));
+        assert_eq!(dmat.size(), (2, 3));
+        assert_eq!(mat.as_slice(), dmat.as_slice());
+        assert_eq!(mat.as_slice(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!((mat[[1, 2]], dmat[[1, 2]]), (6, 6));
+    }
+
+    #[test]
+    fn shared_operators() {
+        let mat = Matrix::<i32, 2, 2>::new([[1, 2], [3, 4]]);
+        let dmat = DMatrix::from(mat);
+        assert_eq!((mat + mat * 2 - -mat).as_slice(), [4, 8, 12, 16]);
+        assert_eq!(
+            (dmat.clone() + dmat.clone() * 2 - -dmat.clone()).as_slice(),
+            [4, 8, 12, 16]
+        );
+        assert_eq!((dmat.clone() / 2).as_slice(), [0, 1, 1, 2]);
+        assert_eq!((dmat.clone() % 2).as_slice(), [1, 0, 1, 0]);
+
+        let mut product = dmat.clone();
+        product *= dmat.clone();
+        assert_eq!(product.as_slice(), (mat * mat).as_slice());
+        assert_eq!(format!("{}", mat), format!("{}", dmat));
+    }
+
+    #[test]
+    fn borrowed() {
+        let entries = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let lhs = GenericMatrix {
+            data: SliceStorage::new(&entries, (3, 2)),
+        };
+        let rhs = DMatrix::new(vec![vec![1.0, -1.0]]);
+        let mut buffer = [0.0; 3];
+        let mut out = GenericMatrix {
+            data: SliceStorageMut::new(&mut buffer, (3, 1)),
+        };
+        lhs.mul_to(&rhs, &mut out);
+        out[[0, 0]] += 1.0;
+        assert_eq!(buffer, [-2.0, -3.0, -3.0]);
+        assert_eq!(lhs[[2, 1]], 6.0);
+    }
+
+    #[test]
+    fn strided() {
+        let mat = Matrix::<f64, 2, 3>::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let mut dmat = DMatrix::from(mat);
+        assert_eq!(dmat.as_ptr(), dmat.as_slice().as_ptr());
+
+        let transposed = dmat.transpose_view();
+        assert_eq!(transposed.size(), (3, 2));
+        assert_eq!(transposed.strides(), (2, 1));
+        assert!(!transposed.is_contiguous());
+        assert_eq!(transposed.as_ptr(), dmat.as_ptr());
+        assert_eq!(transposed[[2, 0]], 5.0);
+        assert_eq!(
+            transposed.clone_owned().as_slice(),
+            mat.transpose().as_slice()
+        );
+        assert_eq!(transposed.clone_owned(), mat.transpose_view().clone_owned());
+        assert_eq!(transposed.frobenius_norm(), dmat.frobenius_norm());
+
+        let mut product = DMatrix::from_element((2, 2), 0.0);
+        dmat.mul_to(&transposed, &mut product);
+        assert_eq!(product, DMatrix::from(mat * mat.transpose()));
+
+        dmat.transpose_view_mut().apply(|e| -e);
+        dmat.transpose_view_mut()[[1, 0]] = 0.0;
+        assert_eq!(dmat.as_slice(), [-1.0, -2.0, 0.0, -4.0, -5.0, -6.0]);
+    }
+
+    #[test]
+    fn view_equality() {
+        let m = DMatrix::new(vec![vec![1, 2], vec![3, 4]]);
+        let n = DMatrix::new(vec![vec![1, 2], vec![5, 6]]);
+        assert_eq!(m.columns(0..1), n.columns(0..1));
+        assert_ne!(m.columns(1..), n.columns(1..));
+        assert_ne!(m.columns(0..1), m.rows(0..1));
+
+        let transposed = DMatrix::new(vec![vec![1, 3], vec![2, 4]]);
+        assert_eq!(m.as_view(), transposed.transpose_view());
+        let mut copy = m.clone();
+        let mut other = transposed.clone();
+        assert_eq!(copy.as_view_mut(), other.transpose_view_mut());
+        assert_ne!(copy.rows_mut(..1), other.rows_mut(..1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn strides_out_of_bounds() {
+        SliceStorage::with_strides(&[1, 2, 3, 4], (2, 2), (1, 3));
+    }
+
+    #[test]
+    fn norms() {
+        let mat = Matrix::<f64, 2, 2>::new([[1.0, -2.0], [-3.0, 4.0]]);
+        let dmat = DMatrix::from(mat);
+        assert_eq!(mat.frobenius_norm(), 30.0f64.sqrt());
+        assert_eq!(dmat.frobenius_norm(), 30.0f64.sqrt());
+        assert_eq!((mat.one_norm(), dmat.one_norm()), (7.0, 7.0));
+        assert_eq!((mat.infinity_norm(), dmat.infinity_norm()), (6.0, 6.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_shapes() {
+        let _ = DMatrix::from([[1, 2]]) + DMatrix::from([[1], [2]]);
+    }
+}
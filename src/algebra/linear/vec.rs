@@ -1,7 +1,10 @@
+use crate::algebra::linear::bivec::{Bivector2, Bivector3};
 use crate::algebra::linear::mat::Matrix;
 use crate::algebra::linear::scalar::Scalar;
-use fructose::operators::{ClosedAdd, ClosedMul, ClosedSub};
+use fructose::operators::{ClosedAdd, ClosedMul, ClosedOps, ClosedSub};
 use fructose::properties::helpers::identity::{One, Two};
+use fructose::specific::complex::Real;
+use paste::paste;
 use std::ops::{Index, IndexMut};
 
 pub type Point<T, const N: usize> = Vector<T, { N }>;
@@ -49,6 +52,57 @@ impl<T: Scalar + Two + ClosedMul + ClosedAdd + ClosedSub, const N: usize> Vector
     }
 }
 
+impl<T: Scalar + Real + ClosedOps, const N: usize> Vector<T, { N }> {
+    #[inline]
+    pub fn magnitude(&self) -> T {
+        self.dot(*self).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.magnitude();
+        for i in 0..N {
+            self[i] /= mag;
+        }
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut vec = *self;
+        vec.normalize();
+        vec
+    }
+}
+
+impl<T: Scalar + ClosedMul + ClosedSub> Vector<T, 2> {
+    /// exterior product, the oriented area spanned by both vectors
+    #[inline]
+    pub fn wedge(&self, other: Self) -> Bivector2<T> {
+        Bivector2::new(self[0] * other[1] - self[1] * other[0])
+    }
+}
+
+impl<T: Scalar + ClosedMul + ClosedSub> Vector<T, 3> {
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self::from([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+
+    /// exterior product, the oriented plane spanned by both vectors
+    #[inline]
+    pub fn wedge(&self, other: Self) -> Bivector3<T> {
+        Bivector3::new(
+            self[0] * other[1] - self[1] * other[0],
+            self[0] * other[2] - self[2] * other[0],
+            self[1] * other[2] - self[2] * other[1],
+        )
+    }
+}
+
 impl<T, const N: usize> From<[T; N]> for Vector<T, { N }> {
     fn from(rhs: [T; N]) -> Self {
         Point::new([rhs])
@@ -87,7 +141,69 @@ impl<T, const N: usize> IndexMut<usize> for Vector<T, { N }> {
     }
 }
 
-// TODO: reimplement those?
+#[cfg(test)]
+mod vec_tests {
+    use crate::algebra::linear::{Bivector2, Bivector3, Vector};
+
+    #[test]
+    fn letters() {
+        let mut vec = Vector::from([1, 2, 3, 4]);
+        assert_eq!((vec.x(), vec.y(), vec.z(), vec.w()), (1, 2, 3, 4));
+        vec.set_z(7);
+        assert_eq!(vec, Vector::from([1, 2, 7, 4]));
+        assert_eq!(Vector::<i32, 3>::unit_y(), Vector::from([0, 1, 0]));
+        assert_eq!(Vector::<i32, 2>::unit_x(), Vector::from([1, 0]));
+    }
+
+    #[test]
+    fn swizzles() {
+        let vec = Vector::from([1, 2, 3]);
+        assert_eq!(vec.xy(), Vector::from([1, 2]));
+        assert_eq!(vec.zyx(), Vector::from([3, 2, 1]));
+        assert_eq!(vec.xxyy(), Vector::from([1, 1, 2, 2]));
+        assert_eq!(
+            Vector::from([1, 2, 3, 4]).wzyx(),
+            Vector::from([4, 3, 2, 1])
+        );
+        assert_eq!(Vector::from([1, 2]).yyy(), Vector::from([2, 2, 2]));
+    }
+
+    #[test]
+    fn magnitude() {
+        let vec = Vector::from([3.0, 0.0, -4.0]);
+        assert_eq!(vec.magnitude(), 5.0);
+        assert_eq!(vec.normalized(), Vector::from([0.6, 0.0, -0.8]));
+    }
+
+    #[test]
+    fn cross() {
+        let x = Vector::from([1, 0, 0]);
+        let y = Vector::from([0, 1, 0]);
+        assert_eq!(x.cross(y), Vector::from([0, 0, 1]));
+        assert_eq!(y.cross(x), Vector::from([0, 0, -1]));
+
+        let a = Vector::from([2, 3, 4]);
+        let b = Vector::from([5, 6, 7]);
+        assert_eq!(a.cross(b), Vector::from([-3, 6, -3]));
+        assert_eq!(a.cross(b).dot(a), 0);
+    }
+
+    #[test]
+    fn wedge() {
+        let a = Vector::from([2, 3]);
+        let b = Vector::from([5, 6]);
+        assert_eq!(a.wedge(b), Bivector2::new(-3));
+        assert_eq!(b.wedge(a), Bivector2::new(3));
+
+        let a = Vector::from([2, 3, 4]);
+        let b = Vector::from([5, 6, 7]);
+        // the components of the wedge product are the dual of the cross product
+        assert_eq!(a.wedge(b), Bivector3::new(-3, -6, -3));
+        assert_eq!(a.wedge(a), Bivector3::default());
+    }
+}
+
+// TODO: reimplement this?
 // #[macro_export]
 // macro_rules! vec_short {
 //     ($($n:ident => $t:ty),+) => {
@@ -106,50 +222,131 @@ impl<T, const N: usize> IndexMut<usize> for Vector<T, { N }> {
 //         )+
 //     }
 // }
-//
-// /// A macro to add lettered getter and setter functions to a VecN
-// ///
-// /// # Usage
-// /// * `<...>` : replace with values
-// /// * `,+` can repeat infinite times but can not be empty
-// ///
-// /// `<Vector Dimension> => [<<index for data[n]> => <corresponding letter>>,+],+`
-// ///
-// /// # Example
-// /// ```
-// // letters_for_vectors! {
-// //     2 => [0, x; 1, y],
-// //     3 => [0, x; 1, y; 2, z]
-// // }
-// /// ```
-// // TODO: add recursive parsing so the inner expression can be omitted
-// #[macro_export]
-// macro_rules! letters_for_vectors {
-//     ($($e:expr => [$($c:expr, $d:ident);+]),+) => {
-//         $(
-//             impl<T: Scalar> Vector<T, $e> {
-//                 $(
-//                     #[inline]
-//                     pub const fn $d(&self) -> T {
-//                         self.data[$c]
-//                     }
-//
-//                     paste! {
-//                         /// sets the `data[n]` to the value f
-//                         #[inline]
-//                         pub fn [<set_ $d>](&mut self, value: T) {
-//                             self.data[$c] = value;
-//                         }
-//
-//                         // Creates a new unit vector from the corresponding letter
-//                         #[inline]
-//                         pub fn [<unit_ $d>]() -> Self {
-//                             Self::unit($c)
-//                         }
-//                     }
-//
-//                 )+
-//             }
-//         )+
-//     }
-// }
+
+/// A macro to add lettered getter and setter functions to a VecN
+///
+/// # Usage
+/// * `<...>` : replace with values
+/// * `,+` can repeat infinite times but can not be empty
+///
+/// `<Vector Dimension> => [<<index for data[n]> => <corresponding letter>>,+],+`
+///
+/// # Example
+/// ```text
+/// letters_for_vectors! {
+///     2 => [0, x; 1, y],
+///     3 => [0, x; 1, y; 2, z]
+/// }
+/// ```
+macro_rules! letters_for_vectors {
+    ($($e:expr => [$($c:expr, $d:ident);+]),+) => {
+        $(
+            impl<T: Scalar> Vector<T, $e> {
+                $(
+                    #[inline]
+                    pub fn $d(&self) -> T {
+                        self[$c]
+                    }
+
+                    paste! {
+                        /// sets the `data[n]` to the value f
+                        #[inline]
+                        pub fn [<set_ $d>](&mut self, value: T) {
+                            self[$c] = value;
+                        }
+                    }
+                )+
+            }
+
+            impl<T: Scalar + One> Vector<T, $e> {
+                $(
+                    paste! {
+                        /// Creates a new unit vector from the corresponding letter
+                        #[inline]
+                        pub fn [<unit_ $d>]() -> Self {
+                            Self::unit($c)
+                        }
+                    }
+                )+
+            }
+        )+
+    }
+}
+
+/// A macro to add GLSL like swizzles e.g. `v.zyx()` to a VecN
+///
+/// every combination of 2, 3 and 4 of the given letters is generated,
+/// each returns a new vector with the length of the combination
+///
+/// `<Vector Dimension> => [<<letter> <index for data[n]>>,+],+`
+macro_rules! swizzles_for_vectors {
+    ($($e:expr => $letters:tt),+) => {
+        $(
+            impl<T: Scalar> Vector<T, $e> {
+                swizzles_for_vectors!(@two $letters $letters);
+                swizzles_for_vectors!(@three $letters $letters);
+                swizzles_for_vectors!(@four $letters $letters);
+            }
+        )+
+    };
+    (@two [$($a:ident $ai:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@two_b $a $ai; $all); )+
+    };
+    (@two_b $a:ident $ai:tt; [$($b:ident $bi:tt),+]) => {
+        paste! {
+            $(
+                #[inline]
+                pub fn [<$a $b>](&self) -> Vector<T, 2> {
+                    Vector::from([self[$ai], self[$bi]])
+                }
+            )+
+        }
+    };
+    (@three [$($a:ident $ai:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@three_b $a $ai; $all $all); )+
+    };
+    (@three_b $a:ident $ai:tt; [$($b:ident $bi:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@three_c $a $ai, $b $bi; $all); )+
+    };
+    (@three_c $a:ident $ai:tt, $b:ident $bi:tt; [$($c:ident $ci:tt),+]) => {
+        paste! {
+            $(
+                #[inline]
+                pub fn [<$a $b $c>](&self) -> Vector<T, 3> {
+                    Vector::from([self[$ai], self[$bi], self[$ci]])
+                }
+            )+
+        }
+    };
+    (@four [$($a:ident $ai:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@four_b $a $ai; $all $all); )+
+    };
+    (@four_b $a:ident $ai:tt; [$($b:ident $bi:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@four_c $a $ai, $b $bi; $all $all); )+
+    };
+    (@four_c $a:ident $ai:tt, $b:ident $bi:tt; [$($c:ident $ci:tt),+] $all:tt) => {
+        $( swizzles_for_vectors!(@four_d $a $ai, $b $bi, $c $ci; $all); )+
+    };
+    (@four_d $a:ident $ai:tt, $b:ident $bi:tt, $c:ident $ci:tt; [$($d:ident $di:tt),+]) => {
+        paste! {
+            $(
+                #[inline]
+                pub fn [<$a $b $c $d>](&self) -> Vector<T, 4> {
+                    Vector::from([self[$ai], self[$bi], self[$ci], self[$di]])
+                }
+            )+
+        }
+    };
+}
+
+letters_for_vectors! {
+    2 => [0, x; 1, y],
+    3 => [0, x; 1, y; 2, z],
+    4 => [0, x; 1, y; 2, z; 3, w]
+}
+
+swizzles_for_vectors! {
+    2 => [x 0, y 1],
+    3 => [x 0, y 1, z 2],
+    4 => [x 0, y 1, z 2, w 3]
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "algebra")]
 pub mod algebra {
-    use crate::algebra::linear::{Bivector2, Matrix, Rotor2};
+    use crate::algebra::linear::pga::{Line, Motor, Plane, Point};
+    use crate::algebra::linear::{
+        Bivector2, Bivector3, DualQuaternion, Matrix, Quaternion, Rotor2, Rotor3,
+    };
     use bytemuck::{Pod, Zeroable};
     unsafe impl<T: Pod, const M: usize, const N: usize> Pod for Matrix<T, { M }, { N }> {}
     unsafe impl<T: Zeroable, const M: usize, const N: usize> Zeroable for Matrix<T, { M }, { N }> {}
@@ -8,6 +11,30 @@ pub mod algebra {
     unsafe impl<T: Pod> Pod for Bivector2<T> {}
     unsafe impl<T: Zeroable> Zeroable for Bivector2<T> {}
 
+    unsafe impl<T: Pod> Pod for Bivector3<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Bivector3<T> {}
+
     unsafe impl<T: Pod> Pod for Rotor2<T> {}
     unsafe impl<T: Zeroable> Zeroable for Rotor2<T> {}
+
+    unsafe impl<T: Pod> Pod for Rotor3<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Rotor3<T> {}
+
+    unsafe impl<T: Pod> Pod for Quaternion<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Quaternion<T> {}
+
+    unsafe impl<T: Pod> Pod for DualQuaternion<T> {}
+    unsafe impl<T: Zeroable> Zeroable for DualQuaternion<T> {}
+
+    unsafe impl<T: Pod> Pod for Plane<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Plane<T> {}
+
+    unsafe impl<T: Pod> Pod for Line<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Line<T> {}
+
+    unsafe impl<T: Pod> Pod for Point<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Point<T> {}
+
+    unsafe impl<T: Pod> Pod for Motor<T> {}
+    unsafe impl<T: Zeroable> Zeroable for Motor<T> {}
 }
@@ -1,8 +1,9 @@
 #[cfg(feature = "algebra")]
 pub mod algebra {
-    use crate::algebra::linear::{Point, SquareMatrix, Vector};
+    use crate::algebra::linear::{Point, Quaternion, SquareMatrix, Vector};
     use mint::{
-        ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3, Vector2, Vector3, Vector4,
+        ColumnMatrix2, ColumnMatrix3, ColumnMatrix4, Point2, Point3, Quaternion as MintQuaternion,
+        Vector2, Vector3, Vector4,
     };
 
     impl<T> From<Point2<T>> for Point<T, 2> {
@@ -139,4 +140,23 @@ pub mod algebra {
             Self::from(array)
         }
     }
+
+    impl<T> From<MintQuaternion<T>> for Quaternion<T> {
+        fn from(rhs: MintQuaternion<T>) -> Self {
+            Self::new(rhs.v.x, rhs.v.y, rhs.v.z, rhs.s)
+        }
+    }
+
+    impl<T> From<Quaternion<T>> for MintQuaternion<T> {
+        fn from(rhs: Quaternion<T>) -> Self {
+            Self {
+                v: Vector3 {
+                    x: rhs.x,
+                    y: rhs.y,
+                    z: rhs.z,
+                },
+                s: rhs.w,
+            }
+        }
+    }
 }